@@ -10,8 +10,8 @@ use reth_db::init_db;
 use reth_node_builder::NodeBuilder;
 use reth_node_core::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs, EraArgs, NetworkArgs,
-        PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
+        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs, EraArgs, GasPriceArgs,
+        NetworkArgs, PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     node_config::NodeConfig,
     version,
@@ -85,6 +85,10 @@ pub struct NodeCommand<C: ChainSpecParser, Ext: clap::Args + fmt::Debug = NoArgs
     #[command(flatten)]
     pub txpool: TxPoolArgs,
 
+    /// All gas price related arguments with --gasprice prefix
+    #[command(flatten)]
+    pub gas_price: GasPriceArgs,
+
     /// All payload builder related arguments
     #[command(flatten)]
     pub builder: PayloadBuilderArgs,
@@ -160,6 +164,7 @@ where
             network,
             rpc,
             txpool,
+            gas_price,
             builder,
             debug,
             db,
@@ -170,6 +175,8 @@ where
             era,
         } = self;
 
+        gas_price.validate()?;
+
         // set up node config
         let mut node_config = NodeConfig {
             datadir,
@@ -180,6 +187,7 @@ where
             network,
             rpc,
             txpool,
+            gas_price,
             builder,
             debug,
             db,
@@ -39,9 +39,11 @@ use reth_payload_primitives::PayloadTypes;
 use reth_provider::{providers::ProviderFactoryBuilder, EthStorage};
 use reth_rpc::{
     eth::core::{EthApiFor, EthRpcConverterFor},
-    ValidationApi,
+    AdminAddressFilterApi, MinerApi, ValidationApi,
+};
+use reth_rpc_api::servers::{
+    AdminAddressFilterApiServer, BlockSubmissionValidationApiServer, MinerApiServer,
 };
-use reth_rpc_api::servers::BlockSubmissionValidationApiServer;
 use reth_rpc_builder::{config::RethRpcServerConfig, middleware::RethRpcMiddleware};
 use reth_rpc_eth_api::{
     helpers::{
@@ -54,8 +56,8 @@ use reth_rpc_eth_types::{error::FromEvmError, EthApiError};
 use reth_rpc_server_types::RethRpcModule;
 use reth_tracing::tracing::{debug, info};
 use reth_transaction_pool::{
-    blobstore::DiskFileBlobStore, EthTransactionPool, PoolPooledTx, PoolTransaction,
-    TransactionPool, TransactionValidationTaskExecutor,
+    blobstore::DiskFileBlobStore, CoinbaseTipOrdering, EthTransactionPool, PoolPooledTx,
+    PoolTransaction, TransactionPool, TransactionValidationTaskExecutor,
 };
 use revm::context::TxEnv;
 use std::{default::Default, marker::PhantomData, sync::Arc, time::SystemTime};
@@ -304,6 +306,14 @@ where
         let eth_config =
             EthConfigHandler::new(ctx.node.provider().clone(), ctx.node.evm_config().clone());
 
+        let miner_api =
+            ctx.node.pool().shared_minimum_gas_price().map(|minimum_gas_price| {
+                MinerApi::default().with_minimum_gas_price(minimum_gas_price)
+            });
+        let address_filter_api = ctx.node.pool().shared_address_filter().map(|address_filter| {
+            AdminAddressFilterApi::new(ctx.node.pool().clone(), address_filter)
+        });
+
         self.inner
             .launch_add_ons_with(ctx, move |container| {
                 container.modules.merge_if_module_configured(
@@ -315,6 +325,25 @@ where
                     .modules
                     .merge_if_module_configured(RethRpcModule::Eth, eth_config.into_rpc())?;
 
+                // install `miner_setGasPrice`, which updates the minimum gas price of the pool,
+                // only in the authenticated server if the miner namespace is
+                // configured
+                if let Some(miner_api) = miner_api {
+                    if container.modules.module_config().contains_any(&RethRpcModule::Miner) {
+                        debug!(target: "reth::cli", "Installing miner gas price rpc endpoint");
+                        container.auth_module.merge_auth_methods(miner_api.into_rpc())?;
+                    }
+                }
+
+                // install the admin methods that update the address filter of the pool only in
+                // the authenticated server if the admin namespace is configured
+                if let Some(address_filter_api) = address_filter_api {
+                    if container.modules.module_config().contains_any(&RethRpcModule::Admin) {
+                        debug!(target: "reth::cli", "Installing admin address filter rpc endpoint");
+                        container.auth_module.merge_auth_methods(address_filter_api.into_rpc())?;
+                    }
+                }
+
                 Ok(())
             })
             .await
@@ -483,6 +512,11 @@ where
             .set_tx_fee_cap(ctx.config().rpc.rpc_tx_fee_cap)
             .with_max_tx_gas_limit(ctx.config().txpool.max_tx_gas_limit)
             .with_minimum_priority_fee(ctx.config().txpool.minimum_priority_fee)
+            .with_minimum_gas_price(ctx.config().gas_price.min)
+            .with_free_gas_addresses(ctx.config().gas_price.free_gas_addresses.iter().copied())
+            .with_denied_addresses(ctx.config().txpool.denied_addresses.iter().copied())
+            .with_allowed_senders(ctx.config().txpool.allowed_senders.iter().copied())
+            .with_allowed_recipients(ctx.config().txpool.allowed_recipients.iter().copied())
             .with_additional_tasks(ctx.config().txpool.additional_validation_tasks)
             .build_with_tasks(ctx.task_executor().clone(), blob_store.clone());
 
@@ -497,9 +531,12 @@ where
             });
         }
 
+        let ordering = CoinbaseTipOrdering::default()
+            .with_priority_addresses(ctx.config().txpool.priority_addresses.iter().copied());
+
         let transaction_pool = TxPoolBuilder::new(ctx)
             .with_validator(validator)
-            .build_and_spawn_maintenance_task(blob_store, pool_config)?;
+            .build_and_spawn_maintenance_task_with_ordering(ordering, blob_store, pool_config)?;
 
         info!(target: "reth::cli", "Transaction pool initialized");
         debug!(target: "reth::cli", "Spawned txpool maintenance task");
@@ -13,6 +13,7 @@ use alloy_rpc_types_engine::ExecutionData;
 use jsonrpsee::{core::middleware::layer::Either, RpcModule};
 use reth_chain_state::CanonStateSubscriptions;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks, Hardforks};
+use reth_network_api::NetworkInfo;
 use reth_node_api::{
     AddOnsContext, BlockTy, EngineApiValidator, EngineTypes, FullNodeComponents, FullNodeTypes,
     NodeAddOns, NodeTypes, PayloadTypes, PayloadValidator, PrimitivesTy, TreeConfig,
@@ -32,6 +33,7 @@ use reth_rpc_builder::{
 };
 use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_rpc_eth_types::{cache::cache_new_blocks_task, EthConfig, EthStateCache};
+use reth_rpc_layer::{ComponentHealth, HealthCheck, HealthLayer};
 use reth_tokio_util::EventSender;
 use reth_tracing::tracing::{debug, info};
 use std::{
@@ -40,6 +42,27 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+/// [`HealthCheck`] that reports the node as not ready while the network is syncing.
+#[derive(Debug)]
+struct NetworkSyncHealth<Net>(Net);
+
+impl<Net> HealthCheck for NetworkSyncHealth<Net>
+where
+    Net: NetworkInfo + 'static,
+{
+    fn name(&self) -> &'static str {
+        "sync"
+    }
+
+    fn check(&self) -> ComponentHealth {
+        if self.0.is_syncing() {
+            ComponentHealth::not_ready("node is syncing")
+        } else {
+            ComponentHealth::ready()
+        }
+    }
+}
+
 /// Contains the handles to the spawned RPC servers.
 ///
 /// This can be used to access the endpoints of the servers.
@@ -833,6 +856,7 @@ where
             .rpc
             .rpc_server_config()
             .set_rpc_middleware(rpc_middleware)
+            .with_http_health(config.rpc.http_health.then(|| Self::health_layer(&node)))
             .with_tokio_runtime(tokio_runtime);
         let rpc_server_handle = Self::launch_rpc_server_internal(server_config, &modules).await?;
 
@@ -856,6 +880,11 @@ where
         })
     }
 
+    /// Returns the [`HealthLayer`] serving the health of the node's components.
+    fn health_layer(node: &N) -> HealthLayer {
+        HealthLayer::new(vec![Box::new(NetworkSyncHealth(node.network().clone()))])
+    }
+
     /// Launches the RPC servers with the given context and an additional hook for extending
     /// modules. Whether the auth server is launched depends on the CLI configuration.
     pub async fn launch_add_ons_with<F>(
@@ -904,6 +933,7 @@ where
             .rpc
             .rpc_server_config()
             .set_rpc_middleware(rpc_middleware)
+            .with_http_health(config.rpc.http_health.then(|| Self::health_layer(&node)))
             .with_tokio_runtime(tokio_runtime);
 
         let (rpc, auth) = if disable_auth {
@@ -972,7 +1002,8 @@ where
             }),
         );
 
-        let eth_config = config.rpc.eth_config().max_batch_size(config.txpool.max_batch_size());
+        let mut eth_config = config.rpc.eth_config().max_batch_size(config.txpool.max_batch_size());
+        config.gas_price.apply_to_gas_price_oracle_config(&mut eth_config.gas_oracle);
         let ctx = EthApiCtx { components: &node, config: eth_config, cache };
         let eth_api = eth_api_builder.build_eth_api(ctx).await?;
 
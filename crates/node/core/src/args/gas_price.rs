@@ -0,0 +1,165 @@
+//! clap [Args](clap::Args) for gas pricing configuration
+
+use alloy_primitives::{Address, U256};
+use clap::Args;
+use reth_rpc_eth_types::GasPriceOracleConfig;
+
+/// Parameters to configure gas pricing policy of the node.
+///
+/// These settings are shared by the gas price oracle and the transaction pool validator.
+///
+/// Every option can also be provided through the environment variable named after the flag, e.g.
+/// `GASPRICE_MIN` for `--gasprice.min`. Flags take precedence over environment variables.
+#[derive(Debug, Clone, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Gas Price")]
+pub struct GasPriceArgs {
+    /// Minimum gas price in wei.
+    ///
+    /// Transactions that pay a gas price below this value at the base fee of the next block, i.e.
+    /// `min(max fee per gas, base fee + priority fee)`, are rejected by the transaction pool and
+    /// the gas price oracle never suggests a lower price. Must not be greater than
+    /// `--gasprice.max`.
+    ///
+    /// The minimum of the transaction pool can be updated at runtime with `miner_setGasPrice` on
+    /// the authenticated RPC server, which is installed if the `miner` namespace is enabled.
+    #[arg(long = "gasprice.min", value_name = "WEI", env = "GASPRICE_MIN")]
+    pub min: Option<u128>,
+
+    /// Maximum gas price in wei to be suggested by the gas price oracle.
+    ///
+    /// Like `--gasprice.min`, this bounds the suggested priority fee plus the base fee of the
+    /// latest block. Takes precedence over `--gpo.maxprice` if set.
+    #[arg(long = "gasprice.max", value_name = "WEI", env = "GASPRICE_MAX")]
+    pub max: Option<u128>,
+
+    /// Percentage applied to the suggested priority fee while the chain is congested, e.g. `150`
+    /// suggests 1.5x the sampled priority fee.
+    ///
+    /// The chain is considered congested if the latest block used more than half of its gas
+    /// limit.
+    #[arg(
+        long = "gasprice.congestion-factor",
+        value_name = "PERCENT",
+        env = "GASPRICE_CONGESTION_FACTOR"
+    )]
+    pub congestion_factor: Option<u64>,
+
+    /// Sender or recipient addresses whose transactions are exempt from `--gasprice.min` and
+    /// `--txpool.minimum-priority-fee`.
+    ///
+    /// This does not allow zero-price transactions: they must still cover the protocol base fee
+    /// and are ordered like any other transaction.
+    #[arg(
+        long = "gasprice.free-gas-addresses",
+        value_name = "ADDRESS",
+        value_delimiter = ',',
+        env = "GASPRICE_FREE_GAS_ADDRESSES"
+    )]
+    pub free_gas_addresses: Vec<Address>,
+}
+
+impl GasPriceArgs {
+    /// Ensures the configured gas price bounds are consistent.
+    ///
+    /// Both bounds apply to the gas price, i.e. the priority fee plus the base fee, so this returns
+    /// an error if `--gasprice.min` is greater than `--gasprice.max`.
+    pub fn validate(&self) -> eyre::Result<()> {
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            eyre::ensure!(
+                min <= max,
+                "--gasprice.min ({min}) must not be greater than --gasprice.max ({max})"
+            );
+        }
+        Ok(())
+    }
+
+    /// Applies the configured gas price bounds to the given [`GasPriceOracleConfig`].
+    pub fn apply_to_gas_price_oracle_config(&self, config: &mut GasPriceOracleConfig) {
+        if let Some(min) = self.min {
+            config.min_price = Some(U256::from(min));
+        }
+        if let Some(max) = self.max {
+            config.max_price = Some(U256::from(max));
+        }
+        if let Some(congestion_factor) = self.congestion_factor {
+            config.congestion_factor = Some(congestion_factor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_gas_price_args() {
+        let args = CommandParser::<GasPriceArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, GasPriceArgs::default());
+
+        let args = CommandParser::<GasPriceArgs>::parse_from([
+            "reth",
+            "--gasprice.min",
+            "1000000000",
+            "--gasprice.max",
+            "50000000000",
+            "--gasprice.congestion-factor",
+            "150",
+            "--gasprice.free-gas-addresses",
+            "0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002",
+        ])
+        .args;
+        assert_eq!(
+            args,
+            GasPriceArgs {
+                min: Some(1_000_000_000),
+                max: Some(50_000_000_000),
+                congestion_factor: Some(150),
+                free_gas_addresses: vec![Address::with_last_byte(1), Address::with_last_byte(2)],
+            }
+        );
+    }
+
+    #[test]
+    fn test_validate_gas_price_bounds() {
+        let args = CommandParser::<GasPriceArgs>::parse_from([
+            "reth",
+            "--gasprice.min",
+            "2",
+            "--gasprice.max",
+            "1",
+        ])
+        .args;
+        assert!(args.validate().is_err());
+
+        let args = GasPriceArgs { min: Some(1), max: Some(1), ..Default::default() };
+        assert!(args.validate().is_ok());
+        assert!(GasPriceArgs { min: Some(2), ..Default::default() }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_apply_to_gas_price_oracle_config() {
+        let args = GasPriceArgs {
+            min: Some(1),
+            max: Some(2),
+            congestion_factor: Some(150),
+            free_gas_addresses: Vec::new(),
+        };
+        let mut config = GasPriceOracleConfig::default();
+        args.apply_to_gas_price_oracle_config(&mut config);
+        assert_eq!(config.min_price, Some(U256::from(1)));
+        assert_eq!(config.max_price, Some(U256::from(2)));
+        assert_eq!(config.congestion_factor, Some(150));
+
+        let mut config = GasPriceOracleConfig::default();
+        GasPriceArgs::default().apply_to_gas_price_oracle_config(&mut config);
+        assert_eq!(config, GasPriceOracleConfig::default());
+    }
+}
@@ -36,6 +36,10 @@ pub use stage::StageEnum;
 mod gas_price_oracle;
 pub use gas_price_oracle::GasPriceOracleArgs;
 
+/// `GasPriceArgs` for configuring gas pricing policy
+mod gas_price;
+pub use gas_price::GasPriceArgs;
+
 /// TxPoolArgs for configuring the transaction pool
 mod txpool;
 pub use txpool::TxPoolArgs;
@@ -76,6 +76,13 @@ pub struct NetworkArgs {
     #[arg(long, value_name = "IDENTITY", default_value = version_metadata().p2p_client_version.as_ref())]
     pub identity: String,
 
+    /// Suffix appended to the node identity, e.g. the name and build of a fork.
+    ///
+    /// The resulting `<IDENTITY>/<SUFFIX>` is advertised to peers and returned by
+    /// `web3_clientVersion`.
+    #[arg(long = "identity.suffix", value_name = "SUFFIX")]
+    pub identity_suffix: Option<String>,
+
     /// Secret key to use for this node.
     ///
     /// This will also deterministically set the peer ID. If not specified, it will be set in the
@@ -181,6 +188,14 @@ pub struct NetworkArgs {
 }
 
 impl NetworkArgs {
+    /// Returns the client version advertised by the node, the identity with the optional suffix.
+    pub fn client_version(&self) -> String {
+        match &self.identity_suffix {
+            Some(suffix) => format!("{}/{suffix}", self.identity),
+            None => self.identity.clone(),
+        }
+    }
+
     /// Returns the resolved IP address.
     pub fn resolved_addr(&self) -> IpAddr {
         if let Some(ref if_name) = self.net_if {
@@ -271,7 +286,7 @@ impl NetworkArgs {
                 let peer_id = builder.get_peer_id();
                 builder.hello_message(
                     HelloMessageWithProtocols::builder(peer_id)
-                        .client_version(&self.identity)
+                        .client_version(self.client_version())
                         .build(),
                 )
             })
@@ -344,6 +359,7 @@ impl Default for NetworkArgs {
             dns_retries: 0,
             peers_file: None,
             identity: version_metadata().p2p_client_version.to_string(),
+            identity_suffix: None,
             p2p_secret_key: None,
             no_persist_peers: false,
             nat: NatResolver::Any,
@@ -643,6 +659,24 @@ mod tests {
         assert!(args.disable_tx_gossip);
     }
 
+    #[test]
+    fn parse_identity_suffix_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from([
+            "reth",
+            "--identity",
+            "reth/v1.0.0",
+            "--identity.suffix",
+            "xlayer-v0.1.0",
+        ])
+        .args;
+        assert_eq!(args.identity_suffix.as_deref(), Some("xlayer-v0.1.0"));
+        assert_eq!(args.client_version(), "reth/v1.0.0/xlayer-v0.1.0");
+
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--identity", "reth/v1.0.0"]).args;
+        assert_eq!(args.client_version(), "reth/v1.0.0");
+    }
+
     #[test]
     fn network_args_default_sanity_test() {
         let default_args = NetworkArgs::default();
@@ -1,7 +1,7 @@
 //! clap [Args](clap::Args) for RPC related arguments.
 
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr},
     path::PathBuf,
@@ -16,7 +16,9 @@ use clap::{
 use rand::Rng;
 use reth_cli_util::parse_ether_value;
 use reth_rpc_eth_types::builder::config::PendingBlockKind;
-use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
+use reth_rpc_server_types::{
+    constants, ApiKey, MethodRateLimit, RethRpcModule, RpcModuleSelection,
+};
 use url::Url;
 
 use crate::args::{
@@ -60,6 +62,13 @@ pub struct RpcServerArgs {
     #[arg(long = "http.disable-compression", default_value_t = false)]
     pub http_disable_compression: bool,
 
+    /// Serve the `/health` and `/ready` endpoints on the HTTP server.
+    ///
+    /// Both respond with a JSON document describing the status of the node's components. `/ready`
+    /// responds with 503 while the node is syncing.
+    #[arg(long = "http.health", default_value_t = false)]
+    pub http_health: bool,
+
     /// Rpc Modules to be configured for the HTTP server
     #[arg(long = "http.api", value_parser = RpcModuleSelectionValueParser::default())]
     pub http_api: Option<RpcModuleSelection>,
@@ -167,6 +176,23 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-tracing-requests", alias = "rpc-max-tracing-requests", value_name = "COUNT", default_value_t = constants::default_max_tracing_requests())]
     pub rpc_max_tracing_requests: usize,
 
+    /// Per-method rate limits for the HTTP and WS servers, e.g. `eth_getLogs=100,debug_*=10`.
+    ///
+    /// Each limit is `<METHOD>=<REQUESTS_PER_SECOND>` where the method may end with `*` to match
+    /// all methods with that prefix. The first matching limit applies and is shared by all
+    /// connections using the same API key (see `--rpc.api-keys`), or by all connections without
+    /// one from the same IP address. Requests exceeding it are rejected with error code -32005.
+    #[arg(long = "rpc.method-rate-limits", value_name = "LIMITS", value_delimiter = ',')]
+    pub rpc_method_rate_limits: Vec<MethodRateLimit>,
+
+    /// Path to a JSON file with the API keys required by the HTTP and WS servers.
+    ///
+    /// The file maps each key to its settings, e.g.
+    /// `{"<KEY>": {"name": "explorer", "methods": ["eth_*"]}}`. Requests must send the key in the
+    /// `x-api-key` header. Keys without `methods` may call all methods.
+    #[arg(long = "rpc.api-keys", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashMap<String, ApiKey>>)]
+    pub rpc_api_keys: Option<HashMap<String, ApiKey>>,
+
     /// Maximum number of blocks for `trace_filter` requests.
     #[arg(long = "rpc.max-trace-filter-blocks", alias = "rpc-max-trace-filter-blocks", value_name = "COUNT", default_value_t = constants::DEFAULT_MAX_TRACE_FILTER_BLOCKS)]
     pub rpc_max_trace_filter_blocks: u64,
@@ -232,6 +258,14 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.forwarder", alias = "rpc-forwarder", value_name = "FORWARDER")]
     pub rpc_forwarder: Option<Url>,
 
+    /// Execute transactions against the pending state before forwarding them to the
+    /// `--rpc.forwarder` endpoint or, on OP-stack nodes, to the sequencer.
+    ///
+    /// Transactions that revert or halt are rejected with the revert reason instead of being
+    /// forwarded.
+    #[arg(long = "rpc.forwarder-simulate")]
+    pub rpc_forwarder_simulate: bool,
+
     /// Path to file containing disallowed addresses, json-encoded list of strings. Block
     /// validation API will reject blocks containing transactions from these addresses.
     #[arg(long = "builder.disallow", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashSet<Address>>)]
@@ -368,6 +402,7 @@ impl Default for RpcServerArgs {
             http_addr: Ipv4Addr::LOCALHOST.into(),
             http_port: constants::DEFAULT_HTTP_RPC_PORT,
             http_disable_compression: false,
+            http_health: false,
             http_api: None,
             http_corsdomain: None,
             ws: false,
@@ -390,6 +425,8 @@ impl Default for RpcServerArgs {
             rpc_max_subscriptions_per_connection: RPC_DEFAULT_MAX_SUBS_PER_CONN.into(),
             rpc_max_connections: RPC_DEFAULT_MAX_CONNECTIONS.into(),
             rpc_max_tracing_requests: constants::default_max_tracing_requests(),
+            rpc_method_rate_limits: Vec::new(),
+            rpc_api_keys: None,
             rpc_max_trace_filter_blocks: constants::DEFAULT_MAX_TRACE_FILTER_BLOCKS,
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
@@ -402,6 +439,7 @@ impl Default for RpcServerArgs {
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
             rpc_forwarder: None,
+            rpc_forwarder_simulate: false,
             builder_disallow: Default::default(),
         }
     }
@@ -491,6 +529,27 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn test_rpc_method_rate_limits_parse() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.method-rate-limits",
+            "eth_getLogs=100,debug_*=10",
+        ])
+        .args;
+        assert_eq!(
+            args.rpc_method_rate_limits,
+            vec!["eth_getLogs=100".parse().unwrap(), "debug_*=10".parse().unwrap()]
+        );
+
+        assert!(CommandParser::<RpcServerArgs>::try_parse_from([
+            "reth",
+            "--rpc.method-rate-limits",
+            "eth_getLogs"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn test_rpc_tx_fee_cap_parse_integer() {
         let args = CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.txfeecap", "2"]).args;
@@ -518,4 +577,21 @@ mod tests {
         let expected = 1_000_000_000_000_000_000u128;
         assert_eq!(args.rpc_tx_fee_cap, expected); // 1 ETH default cap
     }
+
+    #[test]
+    fn test_rpc_forwarder_simulate() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.forwarder",
+            "http://localhost:8545",
+            "--rpc.forwarder-simulate",
+        ])
+        .args;
+        assert!(args.rpc_forwarder_simulate);
+
+        // also applies to the sequencer of OP-stack nodes, which is configured separately
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.forwarder-simulate"]).args;
+        assert!(args.rpc_forwarder_simulate);
+    }
 }
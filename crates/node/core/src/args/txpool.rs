@@ -97,6 +97,32 @@ pub struct TxPoolArgs {
     /// Flag to allow certain addresses as local.
     #[arg(long = "txpool.locals")]
     pub locals: Vec<Address>,
+    /// Senders and recipients whose transactions are rejected by the transaction pool.
+    ///
+    /// Can be updated at runtime via `admin_addDeniedAddresses` and `admin_removeDeniedAddresses`
+    /// on the authenticated RPC server.
+    #[arg(long = "txpool.denied-addresses", value_name = "ADDRESS", value_delimiter = ',')]
+    pub denied_addresses: Vec<Address>,
+    /// If set, only transactions sent from one of these addresses are accepted into the
+    /// transaction pool.
+    ///
+    /// Denied addresses take precedence. Can be replaced at runtime via `admin_setAllowedSenders`
+    /// on the authenticated RPC server.
+    #[arg(long = "txpool.allowed-senders", value_name = "ADDRESS", value_delimiter = ',')]
+    pub allowed_senders: Vec<Address>,
+    /// If set, only transactions sent to one of these addresses are accepted into the transaction
+    /// pool, which also rejects contract creations.
+    ///
+    /// Denied addresses take precedence. Can be replaced at runtime via
+    /// `admin_setAllowedRecipients` on the authenticated RPC server.
+    #[arg(long = "txpool.allowed-recipients", value_name = "ADDRESS", value_delimiter = ',')]
+    pub allowed_recipients: Vec<Address>,
+    /// Senders and recipients whose transactions are ordered ahead of other transactions with the
+    /// same tip.
+    ///
+    /// Transactions with a higher tip still take precedence.
+    #[arg(long = "txpool.priority-addresses", value_name = "ADDRESS", value_delimiter = ',')]
+    pub priority_addresses: Vec<Address>,
     /// Flag to toggle local transaction propagation.
     #[arg(long = "txpool.no-local-transactions-propagation")]
     pub no_local_transactions_propagation: bool,
@@ -179,6 +205,10 @@ impl Default for TxPoolArgs {
             max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
             no_locals: false,
             locals: Default::default(),
+            denied_addresses: Default::default(),
+            allowed_senders: Default::default(),
+            allowed_recipients: Default::default(),
+            priority_addresses: Default::default(),
             no_local_transactions_propagation: false,
             additional_validation_tasks: DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS,
             pending_tx_listener_buffer_size: PENDING_TX_LISTENER_BUFFER_SIZE,
@@ -270,6 +300,29 @@ mod tests {
         assert_eq!(args.locals, vec![Address::ZERO]);
     }
 
+    #[test]
+    fn txpool_parse_address_filters() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.denied-addresses",
+            "0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002",
+            "--txpool.allowed-senders",
+            "0x0000000000000000000000000000000000000003",
+            "--txpool.allowed-recipients",
+            "0x0000000000000000000000000000000000000005",
+            "--txpool.priority-addresses",
+            "0x0000000000000000000000000000000000000004",
+        ])
+        .args;
+        assert_eq!(
+            args.denied_addresses,
+            vec![Address::with_last_byte(1), Address::with_last_byte(2)]
+        );
+        assert_eq!(args.allowed_senders, vec![Address::with_last_byte(3)]);
+        assert_eq!(args.allowed_recipients, vec![Address::with_last_byte(5)]);
+        assert_eq!(args.priority_addresses, vec![Address::with_last_byte(4)]);
+    }
+
     #[test]
     fn txpool_parse_max_tx_lifetime() {
         // Test with a custom duration
@@ -2,8 +2,8 @@
 
 use crate::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs, NetworkArgs, PayloadBuilderArgs,
-        PruningArgs, RpcServerArgs, TxPoolArgs,
+        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs, GasPriceArgs, NetworkArgs,
+        PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     dirs::{ChainPath, DataDirPath},
     utils::get_single_header,
@@ -134,6 +134,9 @@ pub struct NodeConfig<ChainSpec> {
     /// All txpool related arguments with --txpool prefix
     pub txpool: TxPoolArgs,
 
+    /// All gas price related arguments with --gasprice prefix
+    pub gas_price: GasPriceArgs,
+
     /// All payload builder related arguments
     pub builder: PayloadBuilderArgs,
 
@@ -176,6 +179,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             network: NetworkArgs::default(),
             rpc: RpcServerArgs::default(),
             txpool: TxPoolArgs::default(),
+            gas_price: GasPriceArgs::default(),
             builder: PayloadBuilderArgs::default(),
             debug: DebugArgs::default(),
             db: DatabaseArgs::default(),
@@ -259,6 +263,12 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Set the gas price args for the node
+    pub fn with_gas_price(mut self, gas_price: GasPriceArgs) -> Self {
+        self.gas_price = gas_price;
+        self
+    }
+
     /// Set the builder args for the node
     pub fn with_payload_builder(mut self, builder: PayloadBuilderArgs) -> Self {
         self.builder = builder;
@@ -483,6 +493,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             network: self.network,
             rpc: self.rpc,
             txpool: self.txpool,
+            gas_price: self.gas_price,
             builder: self.builder,
             debug: self.debug,
             db: self.db,
@@ -522,6 +533,7 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             network: self.network.clone(),
             rpc: self.rpc.clone(),
             txpool: self.txpool.clone(),
+            gas_price: self.gas_price.clone(),
             builder: self.builder.clone(),
             debug: self.debug.clone(),
             db: self.db,
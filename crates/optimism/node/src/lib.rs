@@ -35,8 +35,10 @@ pub use reth_optimism_txpool as txpool;
 pub mod utils;
 
 pub use reth_optimism_payload_builder::{
-    self as payload, config::OpDAConfig, OpBuiltPayload, OpPayloadAttributes, OpPayloadBuilder,
-    OpPayloadBuilderAttributes, OpPayloadPrimitives, OpPayloadTypes,
+    self as payload,
+    config::{OpDAConfig, OpGasLimitConfig},
+    OpBuiltPayload, OpPayloadAttributes, OpPayloadBuilder, OpPayloadBuilderAttributes,
+    OpPayloadPrimitives, OpPayloadTypes,
 };
 
 pub use reth_optimism_evm::*;
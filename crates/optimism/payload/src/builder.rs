@@ -1,7 +1,7 @@
 //! Optimism payload builder implementation.
 
 use crate::{
-    config::{OpBuilderConfig, OpDAConfig},
+    config::{OpBuilderConfig, OpDAConfig, OpGasLimitConfig},
     error::OpPayloadBuilderError,
     payload::OpBuiltPayload,
     OpAttributes, OpPayloadBuilderAttributes, OpPayloadPrimitives,
@@ -186,6 +186,7 @@ where
         let ctx = OpPayloadBuilderCtx {
             evm_config: self.evm_config.clone(),
             da_config: self.config.da_config.clone(),
+            gas_limit_config: self.config.gas_limit_config.clone(),
             chain_spec: self.client.chain_spec(),
             config,
             cancel,
@@ -222,6 +223,7 @@ where
         let ctx = OpPayloadBuilderCtx {
             evm_config: self.evm_config.clone(),
             da_config: self.config.da_config.clone(),
+            gas_limit_config: self.config.gas_limit_config.clone(),
             chain_spec: self.client.chain_spec(),
             config,
             cancel: Default::default(),
@@ -537,6 +539,8 @@ pub struct OpPayloadBuilderCtx<
     pub evm_config: Evm,
     /// The DA config for the payload builder
     pub da_config: OpDAConfig,
+    /// The gas limit config for the payload builder, if any
+    pub gas_limit_config: Option<OpGasLimitConfig>,
     /// The chainspec
     pub chain_spec: Arc<ChainSpec>,
     /// How to build the payload.
@@ -659,7 +663,11 @@ where
             Transaction: PoolTransaction<Consensus = TxTy<Evm::Primitives>> + OpPooledTx,
         >,
     ) -> Result<Option<()>, PayloadBuilderError> {
-        let block_gas_limit = builder.evm_mut().block().gas_limit;
+        let gas_limit = builder.evm_mut().block().gas_limit;
+        let block_gas_limit = self
+            .gas_limit_config
+            .as_ref()
+            .map_or(gas_limit, |config| config.block_gas_limit(gas_limit));
         let block_da_limit = self.da_config.max_da_block_size();
         let tx_da_limit = self.da_config.max_da_tx_size();
         let base_fee = builder.evm_mut().block().basefee;
@@ -7,12 +7,20 @@ use std::sync::{atomic::AtomicU64, Arc};
 pub struct OpBuilderConfig {
     /// Data availability configuration for the OP builder.
     pub da_config: OpDAConfig,
+    /// Gas limit configuration for the OP builder, if any.
+    pub gas_limit_config: Option<OpGasLimitConfig>,
 }
 
 impl OpBuilderConfig {
     /// Creates a new OP builder configuration with the given data availability configuration.
     pub const fn new(da_config: OpDAConfig) -> Self {
-        Self { da_config }
+        Self { da_config, gas_limit_config: None }
+    }
+
+    /// Configures the gas limit configuration for the OP builder.
+    pub fn with_gas_limit_config(mut self, gas_limit_config: OpGasLimitConfig) -> Self {
+        self.gas_limit_config = Some(gas_limit_config);
+        self
     }
 
     /// Returns the Data Availability configuration for the OP builder, if it has configured
@@ -100,6 +108,50 @@ struct OpDAConfigInner {
     max_da_block_size: AtomicU64,
 }
 
+/// Contains the gas limit configuration for the OP builder.
+///
+/// The block gas limit is set by the payload attributes, this limits the gas that transactions
+/// from the pool may use in a built block to a lower, effective gas limit.
+///
+/// This type is shareable and can be used to update the gas limit configuration for the OP payload
+/// builder.
+#[derive(Debug, Clone, Default)]
+pub struct OpGasLimitConfig {
+    /// The effective gas limit for built blocks.
+    ///
+    /// 0 means no limit.
+    effective_gas_limit: Arc<AtomicU64>,
+}
+
+impl OpGasLimitConfig {
+    /// Creates a new gas limit configuration with the given effective gas limit.
+    pub fn new(effective_gas_limit: u64) -> Self {
+        let this = Self::default();
+        this.set_effective_gas_limit(effective_gas_limit);
+        this
+    }
+
+    /// Returns the effective gas limit for built blocks, if any.
+    pub fn effective_gas_limit(&self) -> Option<u64> {
+        let val = self.effective_gas_limit.load(std::sync::atomic::Ordering::Relaxed);
+        if val == 0 {
+            None
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Sets the effective gas limit for built blocks. 0 means no limit.
+    pub fn set_effective_gas_limit(&self, effective_gas_limit: u64) {
+        self.effective_gas_limit.store(effective_gas_limit, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Returns the gas limit that transactions may use in a block with the given gas limit.
+    pub fn block_gas_limit(&self, gas_limit: u64) -> u64 {
+        self.effective_gas_limit().map_or(gas_limit, |limit| limit.min(gas_limit))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +169,21 @@ mod tests {
         assert_eq!(da.max_da_block_size(), None);
     }
 
+    #[test]
+    fn test_effective_gas_limit() {
+        let config = OpGasLimitConfig::default();
+        assert_eq!(config.effective_gas_limit(), None);
+        assert_eq!(config.block_gas_limit(30_000_000), 30_000_000);
+
+        config.set_effective_gas_limit(20_000_000);
+        assert_eq!(config.effective_gas_limit(), Some(20_000_000));
+        assert_eq!(config.block_gas_limit(30_000_000), 20_000_000);
+        assert_eq!(config.block_gas_limit(10_000_000), 10_000_000);
+
+        config.set_effective_gas_limit(0);
+        assert_eq!(config.effective_gas_limit(), None);
+    }
+
     #[test]
     fn test_da_constrained() {
         let config = OpBuilderConfig::default();
@@ -8,7 +8,7 @@ use reth_optimism_primitives::DepositReceipt;
 use reth_primitives_traits::SignedTransaction;
 use reth_rpc_eth_api::{
     helpers::{spec::SignersForRpc, EthTransactions, LoadTransaction},
-    try_into_op_tx_info, FromEthApiError, RpcConvert, RpcNodeCore, TxInfoMapper,
+    try_into_op_tx_info, FromEthApiError, FromEvmError, RpcConvert, RpcNodeCore, TxInfoMapper,
 };
 use reth_rpc_eth_types::utils::recover_raw_transaction;
 use reth_storage_api::{errors::ProviderError, ReceiptProvider};
@@ -20,6 +20,7 @@ use std::fmt::{Debug, Formatter};
 impl<N, Rpc> EthTransactions for OpEthApi<N, Rpc>
 where
     N: RpcNodeCore,
+    OpEthApiError: FromEvmError<N::Evm>,
     Rpc: RpcConvert<Primitives = N::Primitives, Error = OpEthApiError>,
 {
     fn signers(&self) -> &SignersForRpc<Self::Provider, Self::NetworkTypes> {
@@ -32,11 +33,16 @@ where
     async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, Self::Error> {
         let recovered = recover_raw_transaction(&tx)?;
 
+        let pool_transaction = <Self::Pool as TransactionPool>::Transaction::from_pooled(recovered);
+
+        // reject transactions that fail locally instead of forwarding them to the sequencer
+        if self.raw_tx_forwarder().is_some() && self.inner.eth_api.simulate_forwarded_txs() {
+            self.simulate_transaction(pool_transaction.clone_into_consensus()).await?;
+        }
+
         // broadcast raw transaction to subscribers if there is any.
         self.eth_api().broadcast_raw_transaction(tx.clone());
 
-        let pool_transaction = <Self::Pool as TransactionPool>::Transaction::from_pooled(recovered);
-
         // On optimism, transactions are forwarded directly to the sequencer to be included in
         // blocks that it builds.
         if let Some(client) = self.raw_tx_forwarder().as_ref() {
@@ -1,12 +1,30 @@
 //! Miner API extension for OP.
 
+use alloy_consensus::BlockHeader;
 use alloy_primitives::U64;
+use jsonrpsee::proc_macros::rpc;
 use jsonrpsee_core::{async_trait, RpcResult};
 pub use op_alloy_rpc_jsonrpsee::traits::MinerApiExtServer;
+use reth_chainspec::MIN_TRANSACTION_GAS;
 use reth_metrics::{metrics::Gauge, Metrics};
-use reth_optimism_payload_builder::config::OpDAConfig;
+use reth_optimism_payload_builder::config::{OpDAConfig, OpGasLimitConfig};
+use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
+use reth_storage_api::BlockReaderIdExt;
 use tracing::debug;
 
+/// Miner API extension for OP that controls the gas used by pool transactions in built blocks.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "miner"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "miner"))]
+pub trait OpMinerGasLimitApi {
+    /// Sets the effective gas limit for blocks built by this node, 0 removes the limit.
+    ///
+    /// The effective gas limit can't raise the gas limit of a block, which is set by the payload
+    /// attributes. Values below the intrinsic gas of a transaction or above the gas limit of the
+    /// latest block are rejected.
+    #[method(name = "setEffectiveGasLimit")]
+    async fn set_effective_gas_limit(&self, gas_limit: U64) -> RpcResult<bool>;
+}
+
 /// Miner API extension for OP, exposes settings for the data availability configuration via the
 /// `miner_` API.
 #[derive(Debug, Clone)]
@@ -37,6 +55,61 @@ impl MinerApiExtServer for OpMinerExtApi {
     }
 }
 
+/// Miner API extension for OP, exposes settings for the gas limit configuration via the `miner_`
+/// API.
+#[derive(Debug, Clone)]
+pub struct OpMinerGasLimitExtApi<Provider> {
+    provider: Provider,
+    gas_limit_config: OpGasLimitConfig,
+    metrics: OpMinerMetrics,
+}
+
+impl<Provider> OpMinerGasLimitExtApi<Provider> {
+    /// Instantiate the miner API extension with the given, sharable gas limit configuration.
+    ///
+    /// The provider is used to look up the gas limit of the latest block.
+    pub fn new(provider: Provider, gas_limit_config: OpGasLimitConfig) -> Self {
+        Self { provider, gas_limit_config, metrics: OpMinerMetrics::default() }
+    }
+}
+
+#[async_trait]
+impl<Provider> OpMinerGasLimitApiServer for OpMinerGasLimitExtApi<Provider>
+where
+    Provider: BlockReaderIdExt + 'static,
+{
+    /// Handler for `miner_setEffectiveGasLimit` RPC method.
+    async fn set_effective_gas_limit(&self, gas_limit: U64) -> RpcResult<bool> {
+        let gas_limit = gas_limit.to::<u64>();
+        if gas_limit != 0 {
+            if gas_limit < MIN_TRANSACTION_GAS {
+                return Err(invalid_params_rpc_err(format!(
+                    "effective gas limit {gas_limit} is below the minimum of {MIN_TRANSACTION_GAS}"
+                )))
+            }
+
+            let block_gas_limit = self
+                .provider
+                .latest_header()
+                .map_err(|err| internal_rpc_err(err.to_string()))?
+                .ok_or_else(|| internal_rpc_err("latest header not found"))?
+                .gas_limit();
+            if gas_limit > block_gas_limit {
+                return Err(invalid_params_rpc_err(format!(
+                    "effective gas limit {gas_limit} exceeds the block gas limit of {block_gas_limit}"
+                )))
+            }
+        }
+
+        debug!(target: "rpc", "Setting effective gas limit: {}", gas_limit);
+        self.gas_limit_config.set_effective_gas_limit(gas_limit);
+
+        self.metrics.set_effective_gas_limit(gas_limit);
+
+        Ok(true)
+    }
+}
+
 /// Optimism miner metrics
 #[derive(Metrics, Clone)]
 #[metrics(scope = "optimism_rpc.miner")]
@@ -45,6 +118,8 @@ pub struct OpMinerMetrics {
     max_da_tx_size: Gauge,
     /// Max DA block size set on the miner
     max_da_block_size: Gauge,
+    /// Effective gas limit set on the miner
+    effective_gas_limit: Gauge,
 }
 
 impl OpMinerMetrics {
@@ -59,4 +134,36 @@ impl OpMinerMetrics {
     pub fn set_max_da_block_size(&self, size: u64) {
         self.max_da_block_size.set(size as f64);
     }
+
+    /// Sets the effective gas limit gauge value
+    #[inline]
+    pub fn set_effective_gas_limit(&self, gas_limit: u64) {
+        self.effective_gas_limit.set(gas_limit as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use alloy_primitives::B256;
+    use reth_provider::test_utils::MockEthProvider;
+
+    #[tokio::test]
+    async fn set_effective_gas_limit_within_bounds() {
+        let provider = MockEthProvider::default();
+        provider.add_header(B256::random(), Header { gas_limit: 30_000_000, ..Default::default() });
+        let gas_limit_config = OpGasLimitConfig::default();
+        let api = OpMinerGasLimitExtApi::new(provider, gas_limit_config.clone());
+
+        assert!(api.set_effective_gas_limit(U64::from(MIN_TRANSACTION_GAS - 1)).await.is_err());
+        assert!(api.set_effective_gas_limit(U64::from(30_000_001)).await.is_err());
+        assert_eq!(gas_limit_config.effective_gas_limit(), None);
+
+        assert!(api.set_effective_gas_limit(U64::from(20_000_000)).await.unwrap());
+        assert_eq!(gas_limit_config.effective_gas_limit(), Some(20_000_000));
+
+        assert!(api.set_effective_gas_limit(U64::ZERO).await.unwrap());
+        assert_eq!(gas_limit_config.effective_gas_limit(), None);
+    }
 }
@@ -10,8 +10,10 @@ use reth_primitives_traits::{
 };
 use reth_storage_api::{AccountInfoReader, BlockReaderIdExt, StateProviderFactory};
 use reth_transaction_pool::{
-    error::InvalidPoolTransactionError, EthPoolTransaction, EthTransactionValidator,
-    TransactionOrigin, TransactionValidationOutcome, TransactionValidator,
+    error::InvalidPoolTransactionError,
+    validate::{AddressFilter, MinimumGasPrice},
+    EthPoolTransaction, EthTransactionValidator, TransactionOrigin, TransactionValidationOutcome,
+    TransactionValidator,
 };
 use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
@@ -337,6 +339,14 @@ where
             new_tip_block.body().transactions().first(),
         );
     }
+
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        self.inner.shared_minimum_gas_price()
+    }
+
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        self.inner.shared_address_filter()
+    }
 }
 
 /// Keeps track of whether certain forks are activated
@@ -1,3 +1,4 @@
+use alloy_primitives::Address;
 use alloy_rpc_types_admin::{NodeInfo, PeerInfo};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_network_peers::{AnyNode, NodeRecord};
@@ -45,4 +46,45 @@ pub trait AdminApi {
     /// Returns the ENR of the node.
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<NodeInfo>;
+
+    /// Replaces the log filter of a log output of the node at runtime.
+    ///
+    /// Accepts comma-separated filter directives in the same format as `--log.<output>.filter`,
+    /// e.g. `net=debug`. The output is one of `stdout` (default), `file` or `journald`; the
+    /// filters of other outputs are not changed. The new filter applies until the node is
+    /// restarted or the filter is replaced again.
+    #[method(name = "setLogLevel")]
+    fn set_log_level(&self, filter: String, output: Option<String>) -> RpcResult<bool>;
+}
+
+/// Admin namespace rpc interface to update the sender and recipient deny and allow lists of the
+/// transaction pool at runtime.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait AdminAddressFilterApi {
+    /// Rejects transactions sent from or to the given addresses and evicts them from the pool.
+    ///
+    /// Returns the number of evicted transactions.
+    #[method(name = "addDeniedAddresses")]
+    fn add_denied_addresses(&self, addresses: Vec<Address>) -> RpcResult<usize>;
+
+    /// Stops rejecting transactions sent from or to the given addresses.
+    ///
+    /// Returns true if any of the addresses was denied.
+    #[method(name = "removeDeniedAddresses")]
+    fn remove_denied_addresses(&self, addresses: Vec<Address>) -> RpcResult<bool>;
+
+    /// Replaces the addresses that transactions must be sent from and evicts transactions from
+    /// other senders from the pool. An empty list accepts all senders.
+    ///
+    /// Returns the number of evicted transactions.
+    #[method(name = "setAllowedSenders")]
+    fn set_allowed_senders(&self, addresses: Vec<Address>) -> RpcResult<usize>;
+
+    /// Replaces the addresses that transactions must be sent to and evicts transactions to other
+    /// recipients from the pool. An empty list accepts all recipients.
+    ///
+    /// Returns the number of evicted transactions.
+    #[method(name = "setAllowedRecipients")]
+    fn set_allowed_recipients(&self, addresses: Vec<Address>) -> RpcResult<usize>;
 }
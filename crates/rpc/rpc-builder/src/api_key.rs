@@ -0,0 +1,273 @@
+//! API key authentication for the http and ws servers.
+
+use http::{header::CONTENT_TYPE, HeaderValue, StatusCode};
+use jsonrpsee::{
+    core::middleware::{Batch, BatchEntry, BatchEntryErr, Notification},
+    server::{
+        middleware::rpc::{ResponseFuture, RpcServiceT},
+        HttpBody, HttpRequest, HttpResponse,
+    },
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use reth_rpc_server_types::ApiKey;
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+use tracing::trace;
+
+/// Header that carries the API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// JSON-RPC error code for calls to methods that are not allowed for the caller's API key, see
+/// EIP-1474.
+pub const METHOD_NOT_ALLOWED_CODE: i32 = -32004;
+
+/// Http middleware layer that rejects requests without a known API key in the [`API_KEY_HEADER`]
+/// with `401 Unauthorized`.
+///
+/// The [`ApiKey`] of an authenticated request is added to its extensions as `Arc<ApiKey>`, where
+/// it is available to the RPC middleware, see [`ApiKeyMethodFilterLayer`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyLayer {
+    keys: Arc<HashMap<String, Arc<ApiKey>>>,
+}
+
+impl ApiKeyLayer {
+    /// Creates a new [`ApiKeyLayer`] accepting the given keys.
+    pub fn new(keys: impl IntoIterator<Item = (String, ApiKey)>) -> Self {
+        Self {
+            keys: Arc::new(
+                keys.into_iter().map(|(key, api_key)| (key, Arc::new(api_key))).collect(),
+            ),
+        }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyLayer {
+    type Service = ApiKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyService { keys: self.keys.clone(), inner }
+    }
+}
+
+/// Service that authenticates http requests by their API key.
+///
+/// Created by [`ApiKeyLayer`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyService<S> {
+    keys: Arc<HashMap<String, Arc<ApiKey>>>,
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for ApiKeyService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: HttpRequest) -> Self::Future {
+        let api_key = req
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|key| self.keys.get(key))
+            .cloned();
+
+        let Some(api_key) = api_key else {
+            let mut response =
+                HttpResponse::new(HttpBody::from(r#"{"error":"missing or invalid api key"}"#));
+            *response.status_mut() = StatusCode::UNAUTHORIZED;
+            response
+                .headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            return Box::pin(async move { Ok(response) })
+        };
+
+        req.extensions_mut().insert(api_key);
+        Box::pin(self.inner.call(req))
+    }
+}
+
+/// RPC middleware layer that rejects calls to methods that the caller's [`ApiKey`] may not call.
+///
+/// Requests without an [`ApiKey`] in their extensions are not filtered.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct ApiKeyMethodFilterLayer;
+
+impl<S> Layer<S> for ApiKeyMethodFilterLayer {
+    type Service = ApiKeyMethodFilter<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyMethodFilter { inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that enforces the method allowlist of the caller's [`ApiKey`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyMethodFilter<S> {
+    inner: S,
+}
+
+impl<S> ApiKeyMethodFilter<S> {
+    /// Returns the error to respond with if the caller may not call the requested method.
+    fn check(req: &Request<'_>) -> Result<(), ErrorObject<'static>> {
+        let Some(api_key) = req.extensions.get::<Arc<ApiKey>>() else { return Ok(()) };
+        let method = req.method_name();
+        trace!(target: "rpc::api_key", caller = %api_key.name, method, "RPC call");
+
+        if api_key.is_method_allowed(method) {
+            Ok(())
+        } else {
+            Err(ErrorObject::owned(
+                METHOD_NOT_ALLOWED_CODE,
+                format!("method {method} is not allowed for this api key"),
+                None::<()>,
+            ))
+        }
+    }
+
+    /// Replaces the calls of the batch that the caller may not call with an error entry.
+    ///
+    /// Batch entries are not dispatched through [`RpcServiceT::call`], so they are checked
+    /// individually.
+    fn filter_batch(requests: &mut Batch<'_>) {
+        for entry in requests.iter_mut() {
+            let Ok(BatchEntry::Call(req)) = entry else { continue };
+            if let Err(err) = Self::check(req) {
+                *entry = Err(BatchEntryErr::new(req.id(), err));
+            }
+        }
+    }
+}
+
+impl<S> RpcServiceT for ApiKeyMethodFilter<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(&self, req: Request<'a>) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        match Self::check(&req) {
+            Ok(()) => ResponseFuture::future(self.inner.call(req)),
+            Err(err) => ResponseFuture::ready(MethodResponse::error(req.id(), err)),
+        }
+    }
+
+    fn batch<'a>(
+        &self,
+        mut requests: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        Self::filter_batch(&mut requests);
+        self.inner.batch(requests)
+    }
+
+    fn notification<'a>(
+        &self,
+        n: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::Id;
+    use std::convert::Infallible;
+
+    /// Responds with `200 OK` if the request carries an [`ApiKey`] and `500` otherwise.
+    #[derive(Clone)]
+    struct MockRequestService;
+
+    impl Service<HttpRequest> for MockRequestService {
+        type Response = HttpResponse;
+        type Error = Infallible;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: HttpRequest) -> Self::Future {
+            let mut response = HttpResponse::new(HttpBody::empty());
+            if req.extensions().get::<Arc<ApiKey>>().is_none() {
+                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+            }
+            std::future::ready(Ok(response))
+        }
+    }
+
+    async fn status(key: Option<&str>) -> StatusCode {
+        let api_key = ApiKey { name: "explorer".to_string(), methods: Vec::new() };
+        let mut service =
+            ApiKeyLayer::new([("secret".to_string(), api_key)]).layer(MockRequestService);
+        let mut request = HttpRequest::builder().uri("/");
+        if let Some(key) = key {
+            request = request.header(API_KEY_HEADER, key);
+        }
+        service.call(request.body(HttpBody::empty()).unwrap()).await.unwrap().status()
+    }
+
+    fn request<'a>(method: &'a str, api_key: Option<&ApiKey>) -> Request<'a> {
+        let mut req = Request::borrowed(method, None, Id::Number(1));
+        if let Some(api_key) = api_key {
+            req.extensions.insert(Arc::new(api_key.clone()));
+        }
+        req
+    }
+
+    #[test]
+    fn test_method_filter_rejects_denied_method() {
+        let api_key = ApiKey { name: "explorer".to_string(), methods: vec!["eth_*".to_string()] };
+
+        assert!(ApiKeyMethodFilter::<()>::check(&request("eth_getLogs", Some(&api_key))).is_ok());
+
+        let err =
+            ApiKeyMethodFilter::<()>::check(&request("debug_traceTransaction", Some(&api_key)))
+                .unwrap_err();
+        assert_eq!(err.code(), METHOD_NOT_ALLOWED_CODE);
+
+        // requests without an api key are not filtered
+        assert!(ApiKeyMethodFilter::<()>::check(&request("debug_traceTransaction", None)).is_ok());
+    }
+
+    #[test]
+    fn test_method_filter_batch() {
+        let api_key = ApiKey { name: "explorer".to_string(), methods: vec!["eth_*".to_string()] };
+        let mut batch = Batch::from(vec![
+            Ok(BatchEntry::Call(request("eth_blockNumber", Some(&api_key)))),
+            Ok(BatchEntry::Call(request("admin_peers", Some(&api_key)))),
+            Ok(BatchEntry::Call(request("eth_chainId", Some(&api_key)))),
+        ]);
+
+        ApiKeyMethodFilter::<()>::filter_batch(&mut batch);
+
+        let allowed =
+            batch.iter().map(|entry| matches!(entry, Ok(BatchEntry::Call(_)))).collect::<Vec<_>>();
+        assert_eq!(allowed, [true, false, true]);
+    }
+
+    #[tokio::test]
+    async fn test_api_key_layer() {
+        assert_eq!(status(Some("secret")).await, StatusCode::OK);
+        assert_eq!(status(Some("other")).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(None).await, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -9,7 +9,8 @@ use tower::layer::util::Identity;
 use tracing::{debug, warn};
 
 use crate::{
-    auth::AuthServerConfig, error::RpcError, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
+    api_key::ApiKeyLayer, auth::AuthServerConfig, error::RpcError,
+    method_rate_limiter::MethodRateLimiter, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
     TransportRpcModuleConfig,
 };
 
@@ -105,6 +106,7 @@ impl RethRpcServerConfig for RpcServerArgs {
             .proof_permits(self.rpc_proof_permits)
             .pending_block_kind(self.rpc_pending_block)
             .raw_tx_forwarder(self.rpc_forwarder.clone())
+            .simulate_forwarded_txs(self.rpc_forwarder_simulate)
     }
 
     fn flashbots_config(&self) -> ValidationApiConfig {
@@ -209,6 +211,16 @@ impl RethRpcServerConfig for RpcServerArgs {
                 config.with_ipc(self.ipc_server_builder()).with_ipc_endpoint(self.ipcpath.clone());
         }
 
+        if !self.rpc_method_rate_limits.is_empty() {
+            config = config.with_method_rate_limiter(Some(MethodRateLimiter::new(
+                self.rpc_method_rate_limits.iter().cloned(),
+            )));
+        }
+
+        if let Some(api_keys) = &self.rpc_api_keys {
+            config = config.with_api_keys(Some(ApiKeyLayer::new(api_keys.clone())));
+        }
+
         config
     }
 
@@ -50,7 +50,9 @@ use reth_rpc_eth_api::{
     RpcNodeCore, RpcReceipt, RpcTransaction, RpcTxReq,
 };
 use reth_rpc_eth_types::{receipt::EthReceiptConverter, EthConfig, EthSubscriptionIdProvider};
-use reth_rpc_layer::{AuthLayer, Claims, CompressionLayer, JwtAuthValidator, JwtSecret};
+use reth_rpc_layer::{
+    AuthLayer, Claims, CompressionLayer, HealthLayer, JwtAuthValidator, JwtSecret,
+};
 use reth_storage_api::{
     AccountReader, BlockReader, ChangeSetReader, FullRpcProvider, ProviderBlock,
     StateProviderFactory,
@@ -64,6 +66,7 @@ use std::{
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
+use tokio::net::TcpListener;
 use tower_http::cors::CorsLayer;
 
 pub use cors::CorsDomainError;
@@ -106,6 +109,17 @@ use reth_rpc::eth::sim_bundle::EthSimBundle;
 // Rpc rate limiter
 pub mod rate_limiter;
 
+// Rpc per-method rate limiter
+pub mod method_rate_limiter;
+use method_rate_limiter::MethodRateLimiter;
+
+// Rpc api key authentication
+pub mod api_key;
+use api_key::{ApiKeyLayer, ApiKeyMethodFilterLayer};
+
+// Remote address of http and ws connections
+pub mod remote_addr;
+
 /// A builder type to configure the RPC module: See [`RpcModule`]
 ///
 /// This is the main entrypoint and the easiest way to configure an RPC server.
@@ -1020,6 +1034,8 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     http_addr: Option<SocketAddr>,
     /// Control whether http responses should be compressed
     http_disable_compression: bool,
+    /// Serves the `/health` and `/ready` endpoints on the http server, if configured
+    http_health: Option<HealthLayer>,
     /// Configs for WS server
     ws_server_config: Option<ServerConfigBuilder>,
     /// Allowed CORS Domains for ws.
@@ -1032,6 +1048,10 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     ipc_endpoint: Option<String>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
+    /// Per-method rate limits for the http and ws servers, if configured
+    method_rate_limiter: Option<MethodRateLimiter>,
+    /// API keys required by the http and ws servers, if configured
+    api_keys: Option<ApiKeyLayer>,
     /// Configurable RPC middleware
     rpc_middleware: RpcMiddleware,
 }
@@ -1046,12 +1066,15 @@ impl Default for RpcServerConfig<Identity> {
             http_cors_domains: None,
             http_addr: None,
             http_disable_compression: false,
+            http_health: None,
             ws_server_config: None,
             ws_cors_domains: None,
             ws_addr: None,
             ipc_server_config: None,
             ipc_endpoint: None,
             jwt_secret: None,
+            method_rate_limiter: None,
+            api_keys: None,
             rpc_middleware: Default::default(),
         }
     }
@@ -1110,12 +1133,15 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             http_cors_domains: self.http_cors_domains,
             http_addr: self.http_addr,
             http_disable_compression: self.http_disable_compression,
+            http_health: self.http_health,
             ws_server_config: self.ws_server_config,
             ws_cors_domains: self.ws_cors_domains,
             ws_addr: self.ws_addr,
             ipc_server_config: self.ipc_server_config,
             ipc_endpoint: self.ipc_endpoint,
             jwt_secret: self.jwt_secret,
+            method_rate_limiter: self.method_rate_limiter,
+            api_keys: self.api_keys,
             rpc_middleware,
         }
     }
@@ -1137,6 +1163,25 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configure the [`HealthLayer`] that serves the `/health` and `/ready` endpoints on the http
+    /// server
+    pub fn with_http_health(mut self, health: Option<HealthLayer>) -> Self {
+        self.http_health = health;
+        self
+    }
+
+    /// Configure the [`MethodRateLimiter`] applied to requests on the http and ws servers
+    pub fn with_method_rate_limiter(mut self, rate_limiter: Option<MethodRateLimiter>) -> Self {
+        self.method_rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Configure the [`ApiKeyLayer`] that authenticates requests on the http and ws servers
+    pub fn with_api_keys(mut self, api_keys: Option<ApiKeyLayer>) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+
     /// Configure the cors domains for HTTP
     pub fn with_http_cors(mut self, cors_domain: Option<String>) -> Self {
         self.http_cors_domains = cors_domain;
@@ -1318,6 +1363,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     .set_http_middleware(
                         tower::ServiceBuilder::new()
                             .option_layer(Self::maybe_cors_layer(cors)?)
+                            .option_layer(self.http_health.clone())
+                            .option_layer(self.api_keys.clone())
                             .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
                             .option_layer(Self::maybe_compression_layer(
                                 self.http_disable_compression,
@@ -1333,19 +1380,20 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                                     .map(RpcRequestMetrics::same_port)
                                     .unwrap_or_default(),
                             )
+                            .option_layer(self.api_keys.as_ref().map(|_| ApiKeyMethodFilterLayer))
+                            .option_layer(self.method_rate_limiter.clone())
                             .layer(self.rpc_middleware.clone()),
                     )
                     .set_config(config.build())
-                    .build(http_socket_addr)
-                    .await
-                    .map_err(|err| {
-                        RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
-                    })?;
-                let addr = server.local_addr().map_err(|err| {
+                    .to_service_builder();
+                let listener = TcpListener::bind(http_socket_addr).await.map_err(|err| {
+                    RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
+                })?;
+                let addr = listener.local_addr().map_err(|err| {
                     RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
                 })?;
                 if let Some(module) = modules.http.as_ref().or(modules.ws.as_ref()) {
-                    let handle = server.start(module.clone());
+                    let handle = remote_addr::start_server(listener, server, module.clone());
                     http_handle = Some(handle.clone());
                     ws_handle = Some(handle);
                 }
@@ -1372,23 +1420,27 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
+                        .option_layer(self.api_keys.clone())
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
                 )
                 .set_rpc_middleware(
                     RpcServiceBuilder::default()
                         .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default())
+                        .option_layer(self.api_keys.as_ref().map(|_| ApiKeyMethodFilterLayer))
+                        .option_layer(self.method_rate_limiter.clone())
                         .layer(self.rpc_middleware.clone()),
                 )
-                .build(ws_socket_addr)
+                .to_service_builder();
+            let listener = TcpListener::bind(ws_socket_addr)
                 .await
                 .map_err(|err| RpcError::server_error(err, ServerKind::WS(ws_socket_addr)))?;
 
-            let addr = server
+            let addr = listener
                 .local_addr()
                 .map_err(|err| RpcError::server_error(err, ServerKind::WS(ws_socket_addr)))?;
 
             ws_local_addr = Some(addr);
-            ws_server = Some(server);
+            ws_server = Some((listener, server));
         }
 
         if let Some(config) = self.http_server_config {
@@ -1397,6 +1449,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.http_cors_domains.clone())?)
+                        .option_layer(self.http_health.clone())
+                        .option_layer(self.api_keys.clone())
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
                         .option_layer(Self::maybe_compression_layer(self.http_disable_compression)),
                 )
@@ -1405,22 +1459,35 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                         .layer(
                             modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
                         )
+                        .option_layer(self.api_keys.as_ref().map(|_| ApiKeyMethodFilterLayer))
+                        .option_layer(self.method_rate_limiter.clone())
                         .layer(self.rpc_middleware.clone()),
                 )
-                .build(http_socket_addr)
+                .to_service_builder();
+            let listener = TcpListener::bind(http_socket_addr)
                 .await
                 .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
-            let local_addr = server
+            let local_addr = listener
                 .local_addr()
                 .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
             http_local_addr = Some(local_addr);
-            http_server = Some(server);
+            http_server = Some((listener, server));
         }
 
-        http_handle = http_server
-            .map(|http_server| http_server.start(modules.http.clone().expect("http server error")));
-        ws_handle = ws_server
-            .map(|ws_server| ws_server.start(modules.ws.clone().expect("ws server error")));
+        http_handle = http_server.map(|(listener, server)| {
+            remote_addr::start_server(
+                listener,
+                server,
+                modules.http.clone().expect("http server error"),
+            )
+        });
+        ws_handle = ws_server.map(|(listener, server)| {
+            remote_addr::start_server(
+                listener,
+                server,
+                modules.ws.clone().expect("ws server error"),
+            )
+        });
         Ok(RpcServerHandle {
             http_local_addr,
             ws_local_addr,
@@ -0,0 +1,351 @@
+//! [`jsonrpsee`] helper layer for per-method request rate limits.
+
+use crate::remote_addr::RemoteAddr;
+use jsonrpsee::{
+    core::middleware::{Batch, BatchEntry, BatchEntryErr, Notification},
+    server::middleware::rpc::{ResponseFuture, RpcServiceT},
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use reth_rpc_server_types::{ApiKey, MethodRateLimit};
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+use tower::Layer;
+
+/// JSON-RPC error code for requests rejected because a limit was exceeded, see EIP-1474.
+pub const LIMIT_EXCEEDED_CODE: i32 = -32005;
+
+/// The maximum number of API key and remote IP buckets each limit keeps.
+pub const MAX_CALLER_BUCKETS: usize = 10_000;
+
+/// Rate limiter for the RPC server that enforces a token bucket per [`MethodRateLimit`].
+///
+/// Buckets are shared by all connections of all servers this layer is installed on. Requests
+/// authenticated with an [`ApiKey`] get a bucket per key name, all other requests get a bucket per
+/// [`RemoteAddr`] IP address. Requests without either share one bucket.
+/// A request is limited by the first configured limit that matches its method, requests for
+/// methods without a matching limit are not limited. Rejected requests receive a
+/// [`LIMIT_EXCEEDED_CODE`] error.
+///
+/// Each limit keeps at most [`MAX_CALLER_BUCKETS`] API key and IP buckets. Once that many exist,
+/// buckets that refilled to capacity are dropped before a new one is added, and if there are none,
+/// the least recently used bucket is.
+#[derive(Debug, Clone)]
+pub struct MethodRateLimiter {
+    buckets: Arc<Vec<(MethodRateLimit, Mutex<CallerBuckets>)>>,
+}
+
+impl MethodRateLimiter {
+    /// Creates a new rate limiter with the given limits.
+    pub fn new(limits: impl IntoIterator<Item = MethodRateLimit>) -> Self {
+        let now = Instant::now();
+        let buckets = limits
+            .into_iter()
+            .map(|limit| {
+                let buckets = CallerBuckets::new(limit.per_second.get(), MAX_CALLER_BUCKETS, now);
+                (limit, Mutex::new(buckets))
+            })
+            .collect();
+        Self { buckets: Arc::new(buckets) }
+    }
+
+    /// Takes a token for the given method from the bucket of the caller, returns the exceeded
+    /// limit if there is none left.
+    fn acquire(&self, method: &str, caller: Caller<'_>) -> Result<(), &MethodRateLimit> {
+        let Some((limit, buckets)) = self.buckets.iter().find(|(limit, _)| limit.matches(method))
+        else {
+            return Ok(())
+        };
+        let mut buckets = buckets.lock().unwrap_or_else(|err| err.into_inner());
+        let now = Instant::now();
+        if buckets.get(caller, limit.per_second.get(), now).try_acquire(now) {
+            Ok(())
+        } else {
+            Err(limit)
+        }
+    }
+
+    /// Takes a token for the requested method, returns the error to respond with if there is
+    /// none left.
+    fn check(&self, req: &Request<'_>) -> Result<(), ErrorObject<'static>> {
+        let caller = if let Some(api_key) = req.extensions.get::<Arc<ApiKey>>() {
+            Caller::ApiKey(&api_key.name)
+        } else if let Some(RemoteAddr(addr)) = req.extensions.get::<RemoteAddr>() {
+            Caller::Ip(addr.ip().to_canonical())
+        } else {
+            Caller::Unknown
+        };
+        self.acquire(req.method_name(), caller).map_err(|limit| {
+            ErrorObject::owned(
+                LIMIT_EXCEEDED_CODE,
+                format!("rate limit of {} requests per second exceeded", limit.per_second),
+                None::<()>,
+            )
+        })
+    }
+}
+
+impl<S> Layer<S> for MethodRateLimiter {
+    type Service = MethodRateLimitingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MethodRateLimitingService { inner, rate_limiter: self.clone() }
+    }
+}
+
+/// The caller a request is limited as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Caller<'a> {
+    /// A request authenticated with the [`ApiKey`] of the given name.
+    ApiKey(&'a str),
+    /// A request without an [`ApiKey`] from the given remote IP address.
+    Ip(IpAddr),
+    /// A request without an [`ApiKey`] or [`RemoteAddr`].
+    Unknown,
+}
+
+/// The token buckets of a single limit, one per caller.
+#[derive(Debug)]
+struct CallerBuckets {
+    /// Bucket shared by all requests without an [`ApiKey`] or [`RemoteAddr`].
+    anonymous: TokenBucket,
+    /// Buckets of requests with an [`ApiKey`], by key name.
+    by_name: HashMap<String, TokenBucket>,
+    /// Buckets of requests without an [`ApiKey`], by remote IP address.
+    by_ip: HashMap<IpAddr, TokenBucket>,
+    /// The maximum number of buckets in each of `by_name` and `by_ip`.
+    max_buckets: usize,
+}
+
+impl CallerBuckets {
+    fn new(per_second: u32, max_buckets: usize, now: Instant) -> Self {
+        Self {
+            anonymous: TokenBucket::new(per_second, now),
+            by_name: HashMap::default(),
+            by_ip: HashMap::default(),
+            max_buckets,
+        }
+    }
+
+    /// Returns the bucket of the given caller, creating a full one if it doesn't exist yet.
+    fn get(&mut self, caller: Caller<'_>, per_second: u32, now: Instant) -> &mut TokenBucket {
+        match caller {
+            Caller::ApiKey(name) => {
+                get_or_insert(&mut self.by_name, name, self.max_buckets, per_second, now)
+            }
+            Caller::Ip(ip) => {
+                get_or_insert(&mut self.by_ip, &ip, self.max_buckets, per_second, now)
+            }
+            Caller::Unknown => &mut self.anonymous,
+        }
+    }
+}
+
+/// Returns the bucket of the given key, creating a full one if it doesn't exist yet.
+///
+/// If there are already `max_buckets` buckets, those that refilled to capacity are removed first,
+/// and if there are none, the least recently used bucket is.
+fn get_or_insert<'a, K, Q>(
+    buckets: &'a mut HashMap<K, TokenBucket>,
+    key: &Q,
+    max_buckets: usize,
+    per_second: u32,
+    now: Instant,
+) -> &'a mut TokenBucket
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: ToOwned<Owned = K> + Hash + Eq + ?Sized,
+{
+    if !buckets.contains_key(key) {
+        if buckets.len() >= max_buckets {
+            // a full bucket behaves the same as a new one, so removing it loses no state
+            buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+        if buckets.len() >= max_buckets {
+            if let Some(oldest) = buckets.values().map(|bucket| bucket.refilled_at).min() {
+                buckets.retain(|_, bucket| bucket.refilled_at != oldest);
+            }
+        }
+        buckets.insert(key.to_owned(), TokenBucket::new(per_second, now));
+    }
+    buckets.get_mut(key).expect("bucket was inserted above")
+}
+
+/// Token bucket that refills `capacity` tokens per second.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(per_second: u32, now: Instant) -> Self {
+        let capacity = f64::from(per_second);
+        Self { capacity, tokens: capacity, refilled_at: now }
+    }
+
+    /// Returns the number of tokens the bucket has at the given time.
+    fn tokens_at(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.refilled_at).as_secs_f64();
+        (self.tokens + elapsed * self.capacity).min(self.capacity)
+    }
+
+    /// Returns `true` if the bucket refilled to capacity at the given time.
+    fn is_full(&self, now: Instant) -> bool {
+        self.tokens_at(now) >= self.capacity
+    }
+
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        self.tokens = self.tokens_at(now);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that rejects RPC calls exceeding their method's rate limit.
+#[derive(Debug, Clone)]
+pub struct MethodRateLimitingService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// The rate limiter for RPC requests
+    rate_limiter: MethodRateLimiter,
+}
+
+impl<S> RpcServiceT for MethodRateLimitingService<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(&self, req: Request<'a>) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        match self.rate_limiter.check(&req) {
+            Ok(()) => ResponseFuture::future(self.inner.call(req)),
+            Err(err) => ResponseFuture::ready(MethodResponse::error(req.id(), err)),
+        }
+    }
+
+    fn batch<'a>(
+        &self,
+        mut requests: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        // batch entries are not dispatched through `call`, so they are limited individually here
+        for entry in requests.iter_mut() {
+            let Ok(BatchEntry::Call(req)) = entry else { continue };
+            if let Err(err) = self.rate_limiter.check(req) {
+                *entry = Err(BatchEntryErr::new(req.id(), err));
+            }
+        }
+        self.inner.batch(requests)
+    }
+
+    fn notification<'a>(
+        &self,
+        n: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_token_bucket_refill() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(2, now);
+        assert!(bucket.try_acquire(now));
+        assert!(bucket.try_acquire(now));
+        assert!(!bucket.try_acquire(now));
+
+        assert!(bucket.try_acquire(now + Duration::from_millis(500)));
+        assert!(!bucket.try_acquire(now + Duration::from_millis(500)));
+
+        // never refills above capacity
+        let later = now + Duration::from_secs(10);
+        assert!(bucket.try_acquire(later));
+        assert!(bucket.try_acquire(later));
+        assert!(!bucket.try_acquire(later));
+    }
+
+    #[test]
+    fn test_first_matching_limit_applies() {
+        let limiter = MethodRateLimiter::new([
+            "debug_traceTransaction=2".parse().unwrap(),
+            "debug_*=1".parse().unwrap(),
+        ]);
+
+        assert!(limiter.acquire("debug_traceTransaction", Caller::Unknown).is_ok());
+        assert!(limiter.acquire("debug_traceTransaction", Caller::Unknown).is_ok());
+        assert!(limiter.acquire("debug_traceTransaction", Caller::Unknown).is_err());
+
+        assert!(limiter.acquire("debug_traceBlockByNumber", Caller::Unknown).is_ok());
+        assert!(limiter.acquire("debug_getRawBlock", Caller::Unknown).is_err());
+
+        assert!(limiter.acquire("eth_blockNumber", Caller::Unknown).is_ok());
+    }
+
+    #[test]
+    fn test_limits_are_per_api_key() {
+        let limiter = MethodRateLimiter::new(["eth_getLogs=1".parse().unwrap()]);
+
+        assert!(limiter.acquire("eth_getLogs", Caller::ApiKey("explorer")).is_ok());
+        assert!(limiter.acquire("eth_getLogs", Caller::ApiKey("explorer")).is_err());
+
+        // other keys and requests without a key have their own buckets
+        assert!(limiter.acquire("eth_getLogs", Caller::ApiKey("indexer")).is_ok());
+        assert!(limiter.acquire("eth_getLogs", Caller::Unknown).is_ok());
+        assert!(limiter.acquire("eth_getLogs", Caller::Unknown).is_err());
+    }
+
+    #[test]
+    fn test_limits_are_per_remote_ip() {
+        let limiter = MethodRateLimiter::new(["eth_getLogs=1".parse().unwrap()]);
+        let first = IpAddr::from([10, 0, 0, 1]);
+        let second = IpAddr::from([10, 0, 0, 2]);
+
+        assert!(limiter.acquire("eth_getLogs", Caller::Ip(first)).is_ok());
+        assert!(limiter.acquire("eth_getLogs", Caller::Ip(first)).is_err());
+        assert!(limiter.acquire("eth_getLogs", Caller::Ip(second)).is_ok());
+        assert!(limiter.acquire("eth_getLogs", Caller::Unknown).is_ok());
+    }
+
+    #[test]
+    fn test_caller_buckets_are_bounded() {
+        let now = Instant::now();
+        let mut buckets = CallerBuckets::new(1, 2, now);
+        let ip = |last: u8| Caller::Ip(IpAddr::from([10, 0, 0, last]));
+
+        assert!(buckets.get(ip(1), 1, now).try_acquire(now));
+        let later = now + Duration::from_millis(100);
+        assert!(buckets.get(ip(2), 1, later).try_acquire(later));
+
+        // both buckets are empty, so the least recently used one is evicted
+        assert!(buckets.get(ip(3), 1, later).try_acquire(later));
+        assert_eq!(buckets.by_ip.len(), 2);
+        assert!(!buckets.by_ip.contains_key(&IpAddr::from([10, 0, 0, 1])));
+        assert!(!buckets.get(ip(2), 1, later).try_acquire(later));
+
+        // refilled buckets are removed before new ones are added
+        let refilled = later + Duration::from_secs(1);
+        assert!(buckets.get(ip(4), 1, refilled).try_acquire(refilled));
+        assert_eq!(buckets.by_ip.len(), 1);
+    }
+}
@@ -0,0 +1,83 @@
+//! Serving the http and ws servers with the remote address of each connection.
+
+use hyper::body::{Body, Bytes, Incoming};
+use jsonrpsee::{
+    core::BoxError,
+    server::{
+        serve_with_graceful_shutdown, stop_channel, HttpBody, HttpRequest, HttpResponse,
+        ServerHandle, TowerService, TowerServiceBuilder,
+    },
+    Methods,
+};
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tower::Service;
+use tracing::debug;
+
+/// The remote address of the connection that a request was received on.
+///
+/// Added to the extensions of all requests of the http and ws servers, where it is available to
+/// the RPC middleware, see [`MethodRateLimiter`](crate::method_rate_limiter::MethodRateLimiter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteAddr(pub SocketAddr);
+
+/// Starts serving the `methods` on the listener until the returned [`ServerHandle`] is stopped.
+///
+/// Unlike `Server::start`, this adds the [`RemoteAddr`] of each connection to the extensions of
+/// its requests.
+pub(crate) fn start_server<RpcMiddleware, HttpMiddleware, B>(
+    listener: TcpListener,
+    builder: TowerServiceBuilder<RpcMiddleware, HttpMiddleware>,
+    methods: impl Into<Methods>,
+) -> ServerHandle
+where
+    TowerServiceBuilder<RpcMiddleware, HttpMiddleware>: Clone + Send + 'static,
+    TowerService<RpcMiddleware, HttpMiddleware>:
+        Service<HttpRequest, Response = HttpResponse<B>, Error = BoxError> + Clone + Send + 'static,
+    <TowerService<RpcMiddleware, HttpMiddleware> as Service<HttpRequest>>::Future: Send,
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    let methods = methods.into();
+    let (stop_handle, server_handle) = stop_channel();
+
+    tokio::spawn(async move {
+        let stopped = stop_handle.clone().shutdown();
+        tokio::pin!(stopped);
+
+        loop {
+            let (stream, remote_addr) = tokio::select! {
+                _ = &mut stopped => break,
+                conn = listener.accept() => {
+                    match conn {
+                        Ok(conn) => conn,
+                        Err(err) => {
+                            debug!(target: "rpc", %err, "failed to accept connection");
+                            continue
+                        }
+                    }
+                }
+            };
+
+            if let Err(err) = stream.set_nodelay(true) {
+                debug!(target: "rpc", %err, %remote_addr, "failed to set TCP_NODELAY");
+            }
+
+            let service = builder.clone().build(methods.clone(), stop_handle.clone());
+            let service = tower::service_fn(move |req: HttpRequest<Incoming>| {
+                let mut req = req.map(HttpBody::new);
+                req.extensions_mut().insert(RemoteAddr(remote_addr));
+                service.clone().call(req)
+            });
+
+            let shutdown = stop_handle.clone().shutdown();
+            tokio::spawn(async move {
+                if let Err(err) = serve_with_graceful_shutdown(stream, service, shutdown).await {
+                    debug!(target: "rpc", %err, %remote_addr, "failed to serve connection");
+                }
+            });
+        }
+    });
+
+    server_handle
+}
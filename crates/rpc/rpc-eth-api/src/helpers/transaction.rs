@@ -1,11 +1,13 @@
 //! Database access for `eth_` transaction RPC methods. Loads transaction and receipt data w.r.t.
 //! network.
 
-use super::{EthApiSpec, EthSigner, LoadBlock, LoadReceipt, LoadState, SpawnBlocking};
+use super::{
+    EthApiSpec, EthSigner, LoadBlock, LoadPendingBlock, LoadReceipt, LoadState, SpawnBlocking,
+};
 use crate::{
     helpers::{estimate::EstimateCall, spec::SignersForRpc},
-    FromEthApiError, FullEthApiTypes, IntoEthApiError, RpcNodeCore, RpcNodeCoreExt, RpcReceipt,
-    RpcTransaction,
+    FromEthApiError, FromEvmError, FullEthApiTypes, IntoEthApiError, RpcNodeCore, RpcNodeCoreExt,
+    RpcReceipt, RpcTransaction,
 };
 use alloy_consensus::{
     transaction::{SignerRecoverable, TransactionMeta},
@@ -18,12 +20,14 @@ use alloy_primitives::{Address, Bytes, TxHash, B256};
 use alloy_rpc_types_eth::{BlockNumberOrTag, TransactionInfo};
 use futures::{Future, StreamExt};
 use reth_chain_state::CanonStateSubscriptions;
+use reth_evm::{ConfigureEvm, Evm};
 use reth_node_api::BlockBody;
-use reth_primitives_traits::{RecoveredBlock, SignedTransaction};
+use reth_primitives_traits::{Recovered, RecoveredBlock, SignedTransaction};
+use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc_convert::{transaction::RpcConvert, RpcTxReq};
 use reth_rpc_eth_types::{
-    utils::binary_search, EthApiError, EthApiError::TransactionConfirmationTimeout, SignError,
-    TransactionSource,
+    cache::db::StateProviderTraitObjWrapper, error::ensure_success, utils::binary_search,
+    EthApiError, EthApiError::TransactionConfirmationTimeout, SignError, TransactionSource,
 };
 use reth_storage_api::{
     BlockNumReader, BlockReaderIdExt, ProviderBlock, ProviderReceipt, ProviderTx, ReceiptProvider,
@@ -111,6 +115,37 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
         }
     }
 
+    /// Executes the transaction on top of the pending state without committing it.
+    ///
+    /// Returns an error with the revert reason if the transaction does not execute successfully.
+    /// The nonce is not checked, so transactions queued behind a nonce gap are executed as if they
+    /// were next.
+    fn simulate_transaction(
+        &self,
+        tx: Recovered<ProviderTx<Self::Provider>>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send
+    where
+        Self: LoadState,
+    {
+        async move {
+            let (mut evm_env, at) = self.evm_env_at(BlockId::pending()).await?;
+            evm_env.cfg_env.disable_nonce_check = true;
+
+            self.spawn_blocking_io_fut(move |this| async move {
+                let state = this.state_at_block_id(at).await?;
+                let mut db =
+                    CacheDB::new(StateProviderDatabase::new(StateProviderTraitObjWrapper(&state)));
+
+                let tx_env = this.evm_config().tx_env(tx);
+                let mut evm = this.evm_config().evm_with_env(&mut db, evm_env);
+                let res = evm.transact(tx_env).map_err(Self::Error::from_evm_err)?;
+
+                ensure_success::<_, Self::Error>(res.result).map(drop)
+            })
+            .await
+        }
+    }
+
     /// Returns the transaction by hash.
     ///
     /// Checks the pool and state.
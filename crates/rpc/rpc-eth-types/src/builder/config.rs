@@ -207,6 +207,12 @@ impl EthConfig {
         }
         self
     }
+
+    /// Configures whether transactions are simulated locally before they are forwarded.
+    pub const fn simulate_forwarded_txs(mut self, simulate: bool) -> Self {
+        self.raw_tx_forwarder.simulate = simulate;
+        self
+    }
 }
 
 /// Config for the filter
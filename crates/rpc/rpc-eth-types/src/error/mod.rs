@@ -644,6 +644,12 @@ pub enum RpcInvalidTransactionError {
         /// Minimum required priority fee.
         minimum_priority_fee: u128,
     },
+    /// Transaction gas price is below the minimum required gas price.
+    #[error("transaction gas price below minimum required gas price {minimum_gas_price}")]
+    GasPriceBelowMinimum {
+        /// Minimum required gas price.
+        minimum_gas_price: u128,
+    },
     /// Any other error
     #[error("{0}")]
     Other(Box<dyn ToRpcError>),
@@ -917,6 +923,9 @@ pub enum RpcPoolError {
     /// constraint (blob vs normal tx)
     #[error("address already reserved")]
     AddressAlreadyReserved,
+    /// When the transaction's sender or recipient is not allowed to transact
+    #[error("address {0} is not allowed to transact")]
+    AddressNotAllowed(Address),
     /// Other unspecified error
     #[error(transparent)]
     Other(Box<dyn core::error::Error + Send + Sync>),
@@ -942,7 +951,8 @@ impl From<RpcPoolError> for jsonrpsee_types::error::ErrorObject<'static> {
             RpcPoolError::PoolTransactionError(_) |
             RpcPoolError::Eip4844(_) |
             RpcPoolError::Eip7702(_) |
-            RpcPoolError::AddressAlreadyReserved => {
+            RpcPoolError::AddressAlreadyReserved |
+            RpcPoolError::AddressNotAllowed(_) => {
                 rpc_error_with_code(EthRpcErrorCode::InvalidInput.code(), error.to_string())
             }
             RpcPoolError::Other(other) => internal_rpc_err(other.to_string()),
@@ -997,6 +1007,14 @@ impl From<InvalidPoolTransactionError> for RpcPoolError {
                     minimum_priority_fee,
                 })
             }
+            InvalidPoolTransactionError::GasPriceBelowMinimum { minimum_gas_price } => {
+                Self::Invalid(RpcInvalidTransactionError::GasPriceBelowMinimum {
+                    minimum_gas_price,
+                })
+            }
+            InvalidPoolTransactionError::AddressNotAllowed(address) => {
+                Self::AddressNotAllowed(address)
+            }
         }
     }
 }
@@ -56,6 +56,13 @@ pub struct GasPriceOracleConfig {
 
     /// The minimum gas price, under which the sample will be ignored
     pub ignore_price: Option<U256>,
+
+    /// The minimum gas price to use for the estimate
+    pub min_price: Option<U256>,
+
+    /// The percentage applied to the estimate if the latest block used more than half of its gas
+    /// limit, e.g. `150` for 1.5x
+    pub congestion_factor: Option<u64>,
 }
 
 impl Default for GasPriceOracleConfig {
@@ -69,7 +76,41 @@ impl Default for GasPriceOracleConfig {
             default_suggested_fee: None,
             max_price: Some(DEFAULT_MAX_GAS_PRICE),
             ignore_price: Some(DEFAULT_IGNORE_GAS_PRICE),
+            min_price: None,
+            congestion_factor: None,
+        }
+    }
+}
+
+impl GasPriceOracleConfig {
+    /// Applies the configured congestion factor and gas price bounds to a suggested tip.
+    ///
+    /// The minimum and maximum price bound the resulting gas price, i.e. the tip plus the base fee
+    /// of the given header, so the tip is only raised by the part of the minimum that the base fee
+    /// does not already cover and lowered until the gas price does not exceed the maximum.
+    ///
+    /// The result is not cached, so that the congestion factor is never compounded.
+    fn apply_price_policy(&self, mut tip: U256, header: &impl BlockHeader) -> U256 {
+        // scale the tip if the latest block is congested
+        if let Some(congestion_factor) = self.congestion_factor {
+            if header.gas_used() > header.gas_limit() / 2 {
+                tip = tip.saturating_mul(U256::from(congestion_factor)) / U256::from(100);
+            }
+        }
+
+        let base_fee = U256::from(header.base_fee_per_gas().unwrap_or_default());
+
+        // constrain the gas price to the max price
+        if let Some(max_price) = self.max_price {
+            tip = tip.min(max_price.saturating_sub(base_fee));
+        }
+
+        // constrain the gas price to the min price
+        if let Some(min_price) = self.min_price {
+            tip = tip.max(min_price.saturating_sub(base_fee));
         }
+
+        tip
     }
 }
 
@@ -142,7 +183,9 @@ where
 
         // if we have stored a last price, then we check whether or not it was for the same head
         if inner.last_price.block_hash == header.hash() {
-            return Ok(inner.last_price.price)
+            return Ok(self
+                .oracle_config
+                .apply_price_policy(inner.last_price.price, header.header()))
         }
 
         // if all responses are empty, then we can return a maximum of 2*check_block blocks' worth
@@ -212,7 +255,7 @@ where
 
         inner.last_price = GasPriceOracleResult { block_hash: header.hash(), price };
 
-        Ok(price)
+        Ok(self.oracle_config.apply_price_policy(price, header.header()))
     }
 
     /// Get the `limit` lowest effective tip values for the given block. If the oracle has a
@@ -297,7 +340,9 @@ where
 
         // if we have stored a last price, then we check whether or not it was for the same head
         if inner.last_price.block_hash == header.hash() {
-            return Ok(inner.last_price.price);
+            return Ok(self
+                .oracle_config
+                .apply_price_policy(inner.last_price.price, header.header()));
         }
 
         let mut suggestion = min_suggested_priority_fee;
@@ -327,7 +372,7 @@ where
         // if the block is at capacity, the suggestion must be increased
         if header.gas_used() + max_tx_gas_used > header.gas_limit() {
             let Some(median_tip) = self.get_block_median_tip(header.hash()).await? else {
-                return Ok(suggestion);
+                return Ok(self.oracle_config.apply_price_policy(suggestion, header.header()));
             };
 
             let new_suggestion = median_tip + median_tip / U256::from(10);
@@ -346,7 +391,7 @@ where
 
         inner.last_price = GasPriceOracleResult { block_hash: header.hash(), price: suggestion };
 
-        Ok(suggestion)
+        Ok(self.oracle_config.apply_price_policy(suggestion, header.header()))
     }
 
     /// Get the median tip value for the given block. This is useful for determining
@@ -445,4 +490,47 @@ mod tests {
     fn ignore_price_sanity() {
         assert_eq!(DEFAULT_IGNORE_GAS_PRICE, U256::from(2u64));
     }
+
+    #[test]
+    fn min_price_bounds_gas_price() {
+        let config =
+            GasPriceOracleConfig { min_price: Some(U256::from(100)), ..Default::default() };
+        let header = alloy_consensus::Header { base_fee_per_gas: Some(70), ..Default::default() };
+
+        // tip + base fee below the minimum is raised to the minimum gas price
+        assert_eq!(config.apply_price_policy(U256::from(10), &header), U256::from(30));
+        // tip + base fee above the minimum is kept
+        assert_eq!(config.apply_price_policy(U256::from(50), &header), U256::from(50));
+
+        // the base fee alone covers the minimum
+        let header = alloy_consensus::Header { base_fee_per_gas: Some(200), ..Default::default() };
+        assert_eq!(config.apply_price_policy(U256::from(1), &header), U256::from(1));
+    }
+
+    #[test]
+    fn max_price_bounds_gas_price() {
+        let config =
+            GasPriceOracleConfig { max_price: Some(U256::from(100)), ..Default::default() };
+        let header = alloy_consensus::Header { base_fee_per_gas: Some(70), ..Default::default() };
+
+        // tip + base fee above the maximum is lowered to the maximum gas price
+        assert_eq!(config.apply_price_policy(U256::from(50), &header), U256::from(30));
+        // tip + base fee below the maximum is kept
+        assert_eq!(config.apply_price_policy(U256::from(10), &header), U256::from(10));
+
+        // the base fee alone exceeds the maximum
+        let header = alloy_consensus::Header { base_fee_per_gas: Some(200), ..Default::default() };
+        assert_eq!(config.apply_price_policy(U256::from(10), &header), U256::ZERO);
+    }
+
+    #[test]
+    fn congestion_factor_scales_tip() {
+        let config = GasPriceOracleConfig { congestion_factor: Some(150), ..Default::default() };
+
+        let header = alloy_consensus::Header { gas_limit: 100, gas_used: 51, ..Default::default() };
+        assert_eq!(config.apply_price_policy(U256::from(10), &header), U256::from(15));
+
+        let header = alloy_consensus::Header { gas_limit: 100, gas_used: 50, ..Default::default() };
+        assert_eq!(config.apply_price_policy(U256::from(10), &header), U256::from(10));
+    }
 }
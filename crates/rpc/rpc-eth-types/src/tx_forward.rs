@@ -12,6 +12,14 @@ pub struct ForwardConfig {
     ///
     /// Default is `None`
     pub tx_forwarder: Option<Url>,
+    /// Whether transactions are executed against the pending state before they are forwarded.
+    ///
+    /// Transactions that fail to execute are rejected with the revert reason instead of being
+    /// forwarded.
+    ///
+    /// Default is `false`
+    #[serde(default)]
+    pub simulate: bool,
 }
 
 impl ForwardConfig {
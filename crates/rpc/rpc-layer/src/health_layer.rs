@@ -0,0 +1,225 @@
+use http::{header::CONTENT_TYPE, HeaderValue, Method, StatusCode};
+use jsonrpsee_http_client::{HttpBody, HttpRequest, HttpResponse};
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// Path of the liveness endpoint served by the [`HealthLayer`].
+pub const HEALTH_PATH: &str = "/health";
+
+/// Path of the readiness endpoint served by the [`HealthLayer`].
+pub const READY_PATH: &str = "/ready";
+
+/// A component of the node that reports its status to the [`HealthLayer`].
+pub trait HealthCheck: Send + Sync + 'static {
+    /// Name of the component, used as key in the health report.
+    fn name(&self) -> &'static str;
+
+    /// Returns the current status of the component.
+    fn check(&self) -> ComponentHealth;
+}
+
+/// Status of a single component as reported by a [`HealthCheck`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentHealth {
+    /// Whether the component is operational. Reported by both endpoints.
+    pub healthy: bool,
+    /// Whether the component is ready to serve traffic, e.g. the node is synced.
+    pub ready: bool,
+    /// Optional human readable details.
+    pub message: Option<String>,
+}
+
+impl ComponentHealth {
+    /// A healthy component that is ready to serve traffic.
+    pub const fn ready() -> Self {
+        Self { healthy: true, ready: true, message: None }
+    }
+
+    /// A healthy component that is not ready to serve traffic yet.
+    pub fn not_ready(message: impl Into<String>) -> Self {
+        Self { healthy: true, ready: false, message: Some(message.into()) }
+    }
+
+    /// A component that is not operational.
+    pub fn unhealthy(message: impl Into<String>) -> Self {
+        Self { healthy: false, ready: false, message: Some(message.into()) }
+    }
+}
+
+/// This is an Http middleware layer that serves `GET /health` and `GET /ready` from the configured
+/// [`HealthCheck`]s. All other requests are dispatched to the next layer along the chain.
+///
+/// Both endpoints respond with a JSON document containing the status of every component.
+/// `/health` responds with `503 Service Unavailable` if any component is unhealthy, `/ready`
+/// additionally if any component is not ready, e.g. because the node is still syncing.
+#[derive(Clone, Default)]
+pub struct HealthLayer {
+    checks: Arc<Vec<Box<dyn HealthCheck>>>,
+}
+
+impl HealthLayer {
+    /// Creates a new [`HealthLayer`] reporting the given components.
+    pub fn new(checks: Vec<Box<dyn HealthCheck>>) -> Self {
+        Self { checks: Arc::new(checks) }
+    }
+
+    /// Builds the response for the health (`readiness == false`) or readiness endpoint.
+    fn response(&self, readiness: bool) -> HttpResponse {
+        let mut ok = true;
+        let mut components = serde_json::Map::with_capacity(self.checks.len());
+        for check in self.checks.iter() {
+            let health = check.check();
+            ok &= health.healthy && (!readiness || health.ready);
+            components.insert(
+                check.name().to_string(),
+                serde_json::json!({
+                    "healthy": health.healthy,
+                    "ready": health.ready,
+                    "message": health.message,
+                }),
+            );
+        }
+
+        let body = serde_json::json!({
+            "status": if ok { "ok" } else { "unavailable" },
+            "components": components,
+        });
+        let status = if ok { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+
+        let mut response = HttpResponse::new(HttpBody::from(body.to_string()));
+        *response.status_mut() = status;
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+}
+
+impl fmt::Debug for HealthLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HealthLayer")
+            .field("checks", &self.checks.iter().map(|check| check.name()).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<S> Layer<S> for HealthLayer {
+    type Service = HealthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HealthService { layer: self.clone(), inner }
+    }
+}
+
+/// Service that serves the health endpoints.
+///
+/// Created by [`HealthLayer`].
+#[derive(Clone, Debug)]
+pub struct HealthService<S> {
+    layer: HealthLayer,
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for HealthService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: HttpRequest) -> Self::Future {
+        if req.method() == Method::GET {
+            let readiness = match req.uri().path() {
+                HEALTH_PATH => Some(false),
+                READY_PATH => Some(true),
+                _ => None,
+            };
+            if let Some(readiness) = readiness {
+                let response = self.layer.response(readiness);
+                return Box::pin(async move { Ok(response) })
+            }
+        }
+
+        Box::pin(self.inner.call(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use std::convert::Infallible;
+
+    struct StaticCheck(ComponentHealth);
+
+    impl HealthCheck for StaticCheck {
+        fn name(&self) -> &'static str {
+            "static"
+        }
+
+        fn check(&self) -> ComponentHealth {
+            self.0.clone()
+        }
+    }
+
+    #[derive(Clone)]
+    struct MockRequestService;
+
+    impl Service<HttpRequest> for MockRequestService {
+        type Response = HttpResponse;
+        type Error = Infallible;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: HttpRequest) -> Self::Future {
+            std::future::ready(Ok(HttpResponse::new(HttpBody::from("inner".to_string()))))
+        }
+    }
+
+    async fn get(health: ComponentHealth, path: &str) -> (StatusCode, String) {
+        let mut service =
+            HealthLayer::new(vec![Box::new(StaticCheck(health))]).layer(MockRequestService);
+        let request = HttpRequest::builder().uri(path).body(HttpBody::empty()).unwrap();
+        let response = service.call(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_health_endpoints() {
+        let (status, body) = get(ComponentHealth::ready(), HEALTH_PATH).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""status":"ok""#));
+
+        let (status, _) = get(ComponentHealth::not_ready("syncing"), HEALTH_PATH).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = get(ComponentHealth::not_ready("syncing"), READY_PATH).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(body.contains("syncing"));
+
+        let (status, _) = get(ComponentHealth::unhealthy("down"), HEALTH_PATH).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_other_requests_are_forwarded() {
+        let (status, body) = get(ComponentHealth::unhealthy("down"), "/").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "inner");
+    }
+}
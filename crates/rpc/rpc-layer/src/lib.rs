@@ -14,10 +14,14 @@ use jsonrpsee_http_client::HttpResponse;
 mod auth_client_layer;
 mod auth_layer;
 mod compression_layer;
+mod health_layer;
 mod jwt_validator;
 
 pub use auth_layer::{AuthService, ResponseFuture};
 pub use compression_layer::CompressionLayer;
+pub use health_layer::{
+    ComponentHealth, HealthCheck, HealthLayer, HealthService, HEALTH_PATH, READY_PATH,
+};
 
 // Export alloy JWT types
 pub use alloy_rpc_types_engine::{Claims, JwtError, JwtSecret};
@@ -0,0 +1,44 @@
+//! API key settings.
+
+use serde::{Deserialize, Serialize};
+
+use crate::rate_limit::matches_method;
+
+/// Settings of an API key that grants access to the RPC servers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKey {
+    /// Name of the caller using this key, used to identify it in logs and rate limits.
+    pub name: String,
+    /// Methods the key may call, either full method names or prefixes ending with `*`.
+    ///
+    /// All methods are allowed if empty.
+    #[serde(default)]
+    pub methods: Vec<String>,
+}
+
+impl ApiKey {
+    /// Returns true if the key may call the given method.
+    pub fn is_method_allowed(&self, method: &str) -> bool {
+        self.methods.is_empty() ||
+            self.methods.iter().any(|pattern| matches_method(pattern, method))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_key_method_allowlist() {
+        let key: ApiKey = serde_json::from_str(r#"{"name":"explorer"}"#).unwrap();
+        assert!(key.is_method_allowed("debug_traceTransaction"));
+
+        let key: ApiKey =
+            serde_json::from_str(r#"{"name":"explorer","methods":["eth_*","net_version"]}"#)
+                .unwrap();
+        assert!(key.is_method_allowed("eth_getLogs"));
+        assert!(key.is_method_allowed("net_version"));
+        assert!(!key.is_method_allowed("net_peerCount"));
+        assert!(!key.is_method_allowed("debug_traceTransaction"));
+    }
+}
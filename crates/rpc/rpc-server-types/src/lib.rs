@@ -15,4 +15,10 @@ pub mod result;
 mod module;
 pub use module::{RethRpcModule, RpcModuleSelection};
 
+mod rate_limit;
+pub use rate_limit::{MethodRateLimit, MethodRateLimitParseError};
+
+mod api_key;
+pub use api_key::ApiKey;
+
 pub use result::ToRpcResult;
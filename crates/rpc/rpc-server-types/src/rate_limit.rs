@@ -0,0 +1,87 @@
+//! Per-method rate limit settings.
+
+use std::{fmt, num::NonZeroU32, str::FromStr};
+
+/// A rate limit for the RPC methods matching [`MethodRateLimit::method`].
+///
+/// Parsed from `<METHOD>=<REQUESTS_PER_SECOND>`, where the method is either a full method name,
+/// e.g. `eth_getLogs`, or a prefix followed by `*`, e.g. `debug_*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodRateLimit {
+    /// The method name, or a prefix if it ends with `*`.
+    pub method: String,
+    /// Maximum number of requests per second for all matching methods combined.
+    pub per_second: NonZeroU32,
+}
+
+impl MethodRateLimit {
+    /// Returns true if the limit applies to the given method.
+    pub fn matches(&self, method: &str) -> bool {
+        matches_method(&self.method, method)
+    }
+}
+
+/// Returns true if the method matches the pattern, either the full method name or a prefix ending
+/// with `*`.
+pub(crate) fn matches_method(pattern: &str, method: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => method.starts_with(prefix),
+        None => method == pattern,
+    }
+}
+
+impl fmt::Display for MethodRateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.method, self.per_second)
+    }
+}
+
+impl FromStr for MethodRateLimit {
+    type Err = MethodRateLimitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (method, per_second) =
+            s.split_once('=').ok_or_else(|| MethodRateLimitParseError(s.to_string()))?;
+        let method = method.trim();
+        if method.is_empty() {
+            return Err(MethodRateLimitParseError(s.to_string()))
+        }
+        let per_second =
+            per_second.trim().parse().map_err(|_| MethodRateLimitParseError(s.to_string()))?;
+        Ok(Self { method: method.to_string(), per_second })
+    }
+}
+
+/// Error returned when a [`MethodRateLimit`] can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodRateLimitParseError(String);
+
+impl fmt::Display for MethodRateLimitParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid method rate limit `{}`, expected <METHOD>=<REQUESTS_PER_SECOND>", self.0)
+    }
+}
+
+impl std::error::Error for MethodRateLimitParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_method_rate_limit() {
+        let limit: MethodRateLimit = "eth_getLogs=100".parse().unwrap();
+        assert_eq!(limit.method, "eth_getLogs");
+        assert_eq!(limit.per_second.get(), 100);
+        assert!(limit.matches("eth_getLogs"));
+        assert!(!limit.matches("eth_getLogsByHash"));
+
+        let limit: MethodRateLimit = "debug_*=10".parse().unwrap();
+        assert!(limit.matches("debug_traceTransaction"));
+        assert!(!limit.matches("trace_block"));
+
+        assert!("eth_getLogs".parse::<MethodRateLimit>().is_err());
+        assert!("eth_getLogs=0".parse::<MethodRateLimit>().is_err());
+        assert!("=10".parse::<MethodRateLimit>().is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use alloy_genesis::ChainConfig;
+use alloy_primitives::Address;
 use alloy_rpc_types_admin::{
     EthInfo, EthPeerInfo, EthProtocolInfo, NodeInfo, PeerInfo, PeerNetworkInfo, PeerProtocolInfo,
     Ports, ProtocolInfo,
@@ -11,8 +12,10 @@ use reth_chainspec::{EthChainSpec, EthereumHardfork, EthereumHardforks, ForkCond
 use reth_network_api::{NetworkInfo, Peers};
 use reth_network_peers::{id2pk, AnyNode, NodeRecord};
 use reth_network_types::PeerKind;
-use reth_rpc_api::AdminApiServer;
-use reth_rpc_server_types::ToRpcResult;
+use reth_rpc_api::{AdminAddressFilterApiServer, AdminApiServer};
+use reth_rpc_server_types::{result::invalid_params_rpc_err, ToRpcResult};
+use reth_tracing::{set_log_filter, LogOutput};
+use reth_transaction_pool::{validate::AddressFilter, AllPoolTransactions, TransactionPool};
 
 /// `admin` API implementation.
 ///
@@ -182,6 +185,17 @@ where
         })
     }
 
+    /// Handler for `admin_setLogLevel`
+    fn set_log_level(&self, filter: String, output: Option<String>) -> RpcResult<bool> {
+        let output = output
+            .map(|output| output.parse::<LogOutput>())
+            .transpose()
+            .map_err(|err| invalid_params_rpc_err(err.to_string()))?
+            .unwrap_or(LogOutput::Stdout);
+        set_log_filter(output, &filter).map_err(|err| invalid_params_rpc_err(err.to_string()))?;
+        Ok(true)
+    }
+
     /// Handler for `admin_peerEvents`
     async fn subscribe_peer_events(
         &self,
@@ -196,3 +210,98 @@ impl<N, ChainSpec> std::fmt::Debug for AdminApi<N, ChainSpec> {
         f.debug_struct("AdminApi").finish_non_exhaustive()
     }
 }
+
+/// `admin` API implementation to update the address filter of the transaction pool.
+///
+/// Transactions in the pool that are no longer allowed by the updated filter are evicted.
+#[derive(Debug, Clone)]
+pub struct AdminAddressFilterApi<Pool> {
+    /// The transaction pool to evict transactions from.
+    pool: Pool,
+    /// The deny and allow lists enforced by the pool's validator.
+    address_filter: AddressFilter,
+}
+
+impl<Pool> AdminAddressFilterApi<Pool> {
+    /// Creates a new instance of `AdminAddressFilterApi`.
+    pub const fn new(pool: Pool, address_filter: AddressFilter) -> Self {
+        Self { pool, address_filter }
+    }
+}
+
+impl<Pool: TransactionPool> AdminAddressFilterApi<Pool> {
+    /// Removes all transactions from the pool that are sent from or to an address that is not
+    /// allowed by the filter and returns the number of removed transactions.
+    fn evict_not_allowed(&self) -> usize {
+        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+        let hashes = pending
+            .into_iter()
+            .chain(queued)
+            .filter(|tx| self.address_filter.not_allowed_address(tx.sender(), tx.to()).is_some())
+            .map(|tx| *tx.hash())
+            .collect::<Vec<_>>();
+        if hashes.is_empty() {
+            return 0
+        }
+        self.pool.remove_transactions(hashes).len()
+    }
+}
+
+impl<Pool> AdminAddressFilterApiServer for AdminAddressFilterApi<Pool>
+where
+    Pool: TransactionPool + 'static,
+{
+    /// Handler for `admin_addDeniedAddresses`
+    fn add_denied_addresses(&self, addresses: Vec<Address>) -> RpcResult<usize> {
+        self.address_filter.add_denied_addresses(addresses);
+        Ok(self.evict_not_allowed())
+    }
+
+    /// Handler for `admin_removeDeniedAddresses`
+    fn remove_denied_addresses(&self, addresses: Vec<Address>) -> RpcResult<bool> {
+        Ok(self.address_filter.remove_denied_addresses(addresses))
+    }
+
+    /// Handler for `admin_setAllowedSenders`
+    fn set_allowed_senders(&self, addresses: Vec<Address>) -> RpcResult<usize> {
+        self.address_filter.set_allowed_senders(addresses);
+        Ok(self.evict_not_allowed())
+    }
+
+    /// Handler for `admin_setAllowedRecipients`
+    fn set_allowed_recipients(&self, addresses: Vec<Address>) -> RpcResult<usize> {
+        self.address_filter.set_allowed_recipients(addresses);
+        Ok(self.evict_not_allowed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction},
+        TransactionOrigin,
+    };
+
+    #[tokio::test]
+    async fn add_denied_addresses_evicts_pooled_transactions() {
+        let pool = testing_pool();
+        let denied = Address::random();
+        let denied_tx = MockTransaction::eip1559().with_sender(denied);
+        let allowed_tx = MockTransaction::eip1559();
+        let allowed_hash = *allowed_tx.get_hash();
+        pool.add_transaction(TransactionOrigin::External, denied_tx).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, allowed_tx).await.unwrap();
+
+        let filter = AddressFilter::default();
+        let api = AdminAddressFilterApi::new(pool.clone(), filter.clone());
+
+        assert_eq!(api.add_denied_addresses(vec![denied]).unwrap(), 1);
+        assert_eq!(pool.pool_size().total, 1);
+        assert!(pool.contains(&allowed_hash));
+        assert!(filter.denied_addresses().contains(&denied));
+
+        assert!(api.remove_denied_addresses(vec![denied]).unwrap());
+        assert!(filter.denied_addresses().is_empty());
+    }
+}
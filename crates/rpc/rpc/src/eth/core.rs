@@ -170,7 +170,7 @@ where
             (),
             max_batch_size,
             pending_block_kind,
-            raw_tx_forwarder.forwarder_client(),
+            raw_tx_forwarder,
         );
 
         Self { inner: Arc::new(inner) }
@@ -298,6 +298,9 @@ pub struct EthApiInner<N: RpcNodeCore, Rpc: RpcConvert> {
     /// Raw transaction forwarder
     raw_tx_forwarder: Option<RpcClient>,
 
+    /// Whether transactions are simulated before they are forwarded
+    simulate_forwarded_txs: bool,
+
     /// Converter for RPC types.
     tx_resp_builder: Rpc,
 
@@ -334,7 +337,7 @@ where
         next_env: impl PendingEnvBuilder<N::Evm>,
         max_batch_size: usize,
         pending_block_kind: PendingBlockKind,
-        raw_tx_forwarder: Option<RpcClient>,
+        raw_tx_forwarder: ForwardConfig,
     ) -> Self {
         let signers = parking_lot::RwLock::new(Default::default());
         // get the block number of the latest block
@@ -370,7 +373,8 @@ where
             fee_history_cache,
             blocking_task_guard: BlockingTaskGuard::new(proof_permits),
             raw_tx_sender,
-            raw_tx_forwarder,
+            raw_tx_forwarder: raw_tx_forwarder.forwarder_client(),
+            simulate_forwarded_txs: raw_tx_forwarder.simulate,
             tx_resp_builder,
             next_env_builder: Box::new(next_env),
             tx_batch_sender,
@@ -540,6 +544,12 @@ where
     pub const fn raw_tx_forwarder(&self) -> Option<&RpcClient> {
         self.raw_tx_forwarder.as_ref()
     }
+
+    /// Returns true if transactions are simulated locally before they are forwarded.
+    #[inline]
+    pub const fn simulate_forwarded_txs(&self) -> bool {
+        self.simulate_forwarded_txs
+    }
 }
 
 #[cfg(test)]
@@ -31,6 +31,11 @@ where
 
         // forward the transaction to the specific endpoint if configured.
         if let Some(client) = self.raw_tx_forwarder() {
+            // reject transactions that fail locally instead of forwarding them
+            if self.inner.simulate_forwarded_txs() {
+                self.simulate_transaction(pool_transaction.clone_into_consensus()).await?;
+            }
+
             tracing::debug!(target: "rpc::eth", hash = %pool_transaction.hash(), "forwarding raw transaction to forwarder");
             let rlp_hex = hex::encode_prefixed(&tx);
 
@@ -70,12 +75,17 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex_literal::hex, Bytes};
+    use alloy_consensus::{transaction::SignerRecoverable, Header, TxEip1559};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{bytes, hex_literal::hex, Address, Bytes, TxKind, U256};
     use reth_chainspec::ChainSpecProvider;
+    use reth_ethereum_primitives::Transaction;
     use reth_evm_ethereum::EthEvmConfig;
     use reth_network_api::noop::NoopNetwork;
-    use reth_provider::test_utils::NoopProvider;
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider, NoopProvider};
     use reth_rpc_eth_api::helpers::EthTransactions;
+    use reth_rpc_eth_types::{ForwardConfig, RpcInvalidTransactionError};
+    use reth_testing_utils::generators::{self, sign_tx_with_random_key_pair};
     use reth_transaction_pool::{test_utils::testing_pool, TransactionPool};
 
     #[tokio::test]
@@ -119,4 +129,61 @@ mod tests {
         assert!(pool.get(&tx_1_result).is_some(), "tx1 not found in the pool");
         assert!(pool.get(&tx_2_result).is_some(), "tx2 not found in the pool");
     }
+
+    #[tokio::test]
+    async fn send_raw_transaction_rejects_reverting_forwarded_tx() {
+        let provider = MockEthProvider::default();
+        provider.add_header(
+            B256::with_last_byte(1),
+            Header {
+                number: 20_000_000,
+                timestamp: 1_750_000_000,
+                gas_limit: 30_000_000,
+                base_fee_per_gas: Some(7),
+                blob_gas_used: Some(0),
+                excess_blob_gas: Some(0),
+                ..Default::default()
+            },
+        );
+
+        // PUSH1 0x00 PUSH1 0x00 REVERT
+        let reverting = Address::with_last_byte(0x42);
+        provider.add_account(
+            reverting,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(bytes!("60006000fd")),
+        );
+
+        let tx = sign_tx_with_random_key_pair(
+            &mut generators::rng(),
+            Transaction::Eip1559(TxEip1559 {
+                chain_id: 1,
+                gas_limit: 100_000,
+                max_fee_per_gas: 1_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+                to: TxKind::Call(reverting),
+                ..Default::default()
+            }),
+        );
+        provider.add_account(
+            tx.recover_signer().unwrap(),
+            ExtendedAccount::new(0, U256::from(u64::MAX)),
+        );
+
+        let pool = testing_pool();
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        // the forwarder is unreachable, so the transaction must be rejected before forwarding
+        let eth_api = EthApi::builder(provider, pool.clone(), NoopNetwork::default(), evm_config)
+            .raw_tx_forwarder(ForwardConfig {
+                tx_forwarder: Some("http://127.0.0.1:1".parse().unwrap()),
+                simulate: true,
+            })
+            .build();
+
+        let err = eth_api.send_raw_transaction(tx.encoded_2718().into()).await.unwrap_err();
+        assert!(
+            matches!(err, EthApiError::InvalidTransaction(RpcInvalidTransactionError::Revert(_))),
+            "{err:?}"
+        );
+        assert!(pool.is_empty(), "rejected transaction must not be kept in the pool");
+    }
 }
@@ -46,7 +46,7 @@ mod txpool;
 mod validation;
 mod web3;
 
-pub use admin::AdminApi;
+pub use admin::{AdminAddressFilterApi, AdminApi};
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{helpers::SyncListener, EthApi, EthApiBuilder, EthBundle, EthFilter, EthPubSub};
@@ -2,12 +2,25 @@ use alloy_primitives::{Bytes, U128};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_rpc_api::MinerApiServer;
+use reth_transaction_pool::validate::MinimumGasPrice;
 
 /// `miner` API implementation.
 ///
 /// This type provides the functionality for handling `miner` related requests.
 #[derive(Clone, Debug, Default)]
-pub struct MinerApi {}
+pub struct MinerApi {
+    /// The minimum gas price of the transaction pool, updated by `miner_setGasPrice`.
+    minimum_gas_price: Option<MinimumGasPrice>,
+}
+
+impl MinerApi {
+    /// Configures the shareable minimum gas price of the transaction pool that is updated by
+    /// `miner_setGasPrice`.
+    pub fn with_minimum_gas_price(mut self, minimum_gas_price: MinimumGasPrice) -> Self {
+        self.minimum_gas_price = Some(minimum_gas_price);
+        self
+    }
+}
 
 #[async_trait]
 impl MinerApiServer for MinerApi {
@@ -15,11 +28,42 @@ impl MinerApiServer for MinerApi {
         Ok(false)
     }
 
-    fn set_gas_price(&self, _gas_price: U128) -> RpcResult<bool> {
-        Ok(false)
+    fn set_gas_price(&self, gas_price: U128) -> RpcResult<bool> {
+        let Some(minimum_gas_price) = &self.minimum_gas_price else { return Ok(false) };
+        minimum_gas_price.set_minimum_gas_price(Some(gas_price.to()));
+        Ok(true)
     }
 
     fn set_gas_limit(&self, _gas_price: U128) -> RpcResult<bool> {
         Ok(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_transaction_pool::{
+        blobstore::InMemoryBlobStore,
+        validate::{EthTransactionValidator, EthTransactionValidatorBuilder},
+        EthPooledTransaction, TransactionValidator,
+    };
+
+    #[test]
+    fn set_gas_price_updates_pool_minimum() {
+        let validator: EthTransactionValidator<_, EthPooledTransaction> =
+            EthTransactionValidatorBuilder::new(MockEthProvider::default())
+                .with_minimum_gas_price(Some(1_000))
+                .build(InMemoryBlobStore::default());
+        assert_eq!(validator.minimum_gas_price(), Some(1_000));
+
+        let miner = MinerApi::default()
+            .with_minimum_gas_price(validator.shared_minimum_gas_price().unwrap());
+        assert!(miner.set_gas_price(U128::from(2_000)).unwrap());
+        assert_eq!(validator.minimum_gas_price(), Some(2_000));
+
+        // without a minimum gas price to update the call is a no-op
+        assert!(!MinerApi::default().set_gas_price(U128::from(3_000)).unwrap());
+        assert_eq!(validator.minimum_gas_price(), Some(2_000));
+    }
+}
@@ -5,9 +5,12 @@ use std::{
 
 use rolling_file::{RollingConditionBasic, RollingFileAppender};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{filter::Directive, EnvFilter, Layer, Registry};
+use tracing_subscriber::{filter::Directive, reload, EnvFilter, Layer, Registry};
 
-use crate::formatter::LogFormat;
+use crate::{
+    formatter::LogFormat,
+    reload::{LogOutput, ReloadableFilter},
+};
 
 /// A worker guard returned by the file layer.
 ///
@@ -35,6 +38,7 @@ const DEFAULT_ENV_FILTER_DIRECTIVES: [&str; 5] = [
 #[derive(Default)]
 pub struct Layers {
     inner: Vec<BoxedLayer<Registry>>,
+    filters: Vec<ReloadableFilter>,
 }
 
 impl fmt::Debug for Layers {
@@ -57,9 +61,24 @@ impl Layers {
         self.inner.push(layer.boxed());
     }
 
-    /// Consumes the `Layers` instance, returning the inner vector of layers.
-    pub(crate) fn into_inner(self) -> Vec<BoxedLayer<Registry>> {
-        self.inner
+    /// Consumes the `Layers` instance, returning the inner vector of layers and their reloadable
+    /// filters.
+    pub(crate) fn into_inner(self) -> (Vec<BoxedLayer<Registry>>, Vec<ReloadableFilter>) {
+        (self.inner, self.filters)
+    }
+
+    /// Builds the filter of the given output so that it can be replaced at runtime with
+    /// [`set_log_filter`](crate::set_log_filter).
+    fn reloadable_filter(
+        &mut self,
+        output: LogOutput,
+        default_directive: Option<Directive>,
+        directives: &str,
+    ) -> eyre::Result<reload::Layer<EnvFilter, Registry>> {
+        let filter = build_env_filter(default_directive.clone(), directives)?;
+        let (filter, handle) = reload::Layer::new(filter);
+        self.filters.push(ReloadableFilter { output, default_directive, handle });
+        Ok(filter)
     }
 
     /// Adds a journald layer to the layers collection.
@@ -70,7 +89,7 @@ impl Layers {
     /// # Returns
     /// An `eyre::Result<()>` indicating the success or failure of the operation.
     pub(crate) fn journald(&mut self, filter: &str) -> eyre::Result<()> {
-        let journald_filter = build_env_filter(None, filter)?;
+        let journald_filter = self.reloadable_filter(LogOutput::Journald, None, filter)?;
         let layer = tracing_journald::layer()?.with_filter(journald_filter);
         self.add_layer(layer);
         Ok(())
@@ -96,7 +115,7 @@ impl Layers {
         filters: &str,
         color: Option<String>,
     ) -> eyre::Result<()> {
-        let filter = build_env_filter(Some(default_directive), filters)?;
+        let filter = self.reloadable_filter(LogOutput::Stdout, Some(default_directive), filters)?;
         let layer = format.apply(filter, color, None);
         self.add_layer(layer);
         Ok(())
@@ -118,7 +137,7 @@ impl Layers {
         file_info: FileInfo,
     ) -> eyre::Result<FileWorkerGuard> {
         let (writer, guard) = file_info.create_log_writer();
-        let file_filter = build_env_filter(None, filter)?;
+        let file_filter = self.reloadable_filter(LogOutput::File, None, filter)?;
         let layer = format.apply(file_filter, None, Some(writer));
         self.add_layer(layer);
         Ok(guard)
@@ -187,7 +206,7 @@ impl FileInfo {
 ///
 /// # Returns
 /// An `eyre::Result<EnvFilter>` that can be used to configure a tracing subscriber.
-fn build_env_filter(
+pub(crate) fn build_env_filter(
     default_directive: Option<Directive>,
     directives: &str,
 ) -> eyre::Result<EnvFilter> {
@@ -51,10 +51,12 @@ pub use tracing_subscriber;
 // Re-export our types
 pub use formatter::LogFormat;
 pub use layers::{FileInfo, FileWorkerGuard, Layers};
+pub use reload::{set_log_filter, LogOutput};
 pub use test_tracer::TestTracer;
 
 mod formatter;
 mod layers;
+mod reload;
 mod test_tracer;
 
 use tracing::level_filters::LevelFilter;
@@ -226,7 +228,10 @@ impl Tracer for RethTracer {
 
         // The error is returned if the global default subscriber is already set,
         // so it's safe to ignore it
-        let _ = tracing_subscriber::registry().with(layers.into_inner()).try_init();
+        let (layers, filters) = layers.into_inner();
+        if tracing_subscriber::registry().with(layers).try_init().is_ok() {
+            reload::set_filters(filters);
+        }
         Ok(file_guard)
     }
 }
@@ -0,0 +1,124 @@
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+use tracing_subscriber::{filter::Directive, reload, EnvFilter, Registry};
+
+use crate::layers::build_env_filter;
+
+/// Handle to the filter of a layer that was installed by [`Layers`](crate::Layers).
+pub(crate) type FilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Filters of the layers of the global subscriber, set once the subscriber is installed.
+static FILTERS: OnceLock<Vec<ReloadableFilter>> = OnceLock::new();
+
+/// Registers the reloadable filters of the global subscriber.
+///
+/// Only the filters of the first installed subscriber are kept, matching the behavior of the global
+/// default subscriber.
+pub(crate) fn set_filters(filters: Vec<ReloadableFilter>) {
+    let _ = FILTERS.set(filters);
+}
+
+/// The log outputs whose filter can be replaced with [`set_log_filter`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LogOutput {
+    /// Logs written to stdout, configured with `--log.stdout.filter`.
+    Stdout,
+    /// Logs written to the log file, configured with `--log.file.filter`.
+    File,
+    /// Logs sent to journald, configured with `--log.journald.filter`.
+    Journald,
+}
+
+impl FromStr for LogOutput {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stdout" => Ok(Self::Stdout),
+            "file" => Ok(Self::File),
+            "journald" => Ok(Self::Journald),
+            _ => Err(eyre::eyre!("unknown log output `{s}`, expected stdout, file or journald")),
+        }
+    }
+}
+
+impl fmt::Display for LogOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdout => write!(f, "stdout"),
+            Self::File => write!(f, "file"),
+            Self::Journald => write!(f, "journald"),
+        }
+    }
+}
+
+/// The filter of a log output together with the default directive it was built with.
+#[derive(Debug)]
+pub(crate) struct ReloadableFilter {
+    pub(crate) output: LogOutput,
+    pub(crate) default_directive: Option<Directive>,
+    pub(crate) handle: FilterHandle,
+}
+
+impl ReloadableFilter {
+    /// Replaces the filter with one built from the layer's default directive and the given
+    /// `directives`.
+    fn reload(&self, directives: &str) -> eyre::Result<()> {
+        let filter = build_env_filter(self.default_directive.clone(), directives)?;
+        self.handle.reload(filter)?;
+        Ok(())
+    }
+}
+
+/// Replaces the filter of the given log output installed by [`Tracer::init`](crate::Tracer::init)
+/// at runtime.
+///
+/// The `directives` are a comma-separated list of [`EnvFilter`] directives, e.g.
+/// `info,net=debug`. The filter is rebuilt the same way as on startup, i.e. as if the node was
+/// started with `--log.<output>.filter <directives>`: the default directive of the output and the
+/// default directives silencing noisy dependencies are kept. The filters of other outputs are not
+/// changed.
+///
+/// Returns an error if the directives are invalid or if the output is not enabled.
+pub fn set_log_filter(output: LogOutput, directives: &str) -> eyre::Result<()> {
+    FILTERS
+        .get()
+        .and_then(|filters| filters.iter().find(|filter| filter.output == output))
+        .ok_or_else(|| eyre::eyre!("{output} logging is not enabled"))?
+        .reload(directives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::filter::LevelFilter;
+
+    #[test]
+    fn reload_keeps_default_directive() {
+        let (_layer, handle) = reload::Layer::<_, Registry>::new(EnvFilter::default());
+        let filter = ReloadableFilter {
+            output: LogOutput::Stdout,
+            default_directive: Some(LevelFilter::INFO.into()),
+            handle,
+        };
+
+        filter.reload("net=debug").unwrap();
+        let current = filter.handle.with_current(|filter| filter.to_string()).unwrap();
+        let directives = current.split(',').collect::<Vec<_>>();
+        assert!(directives.contains(&"info"), "{current}");
+        assert!(directives.contains(&"net=debug"), "{current}");
+        assert!(directives.contains(&"discv5=off"), "{current}");
+
+        // an invalid filter is rejected and leaves the current filter in place
+        assert!(filter.reload("net=nonsense").is_err());
+        assert_eq!(filter.handle.with_current(|filter| filter.to_string()).unwrap(), current);
+    }
+
+    #[test]
+    fn parse_log_output() {
+        for output in [LogOutput::Stdout, LogOutput::File, LogOutput::Journald] {
+            assert_eq!(output.to_string().parse::<LogOutput>().unwrap(), output);
+        }
+        assert!("stderr".parse::<LogOutput>().is_err());
+    }
+}
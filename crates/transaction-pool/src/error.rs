@@ -273,6 +273,16 @@ pub enum InvalidPoolTransactionError {
         /// Minimum required priority fee.
         minimum_priority_fee: u128,
     },
+    /// The gas price the transaction pays at the base fee of the next block is below the
+    /// configured minimum gas price.
+    #[error("transaction gas price below minimum required gas price {minimum_gas_price}")]
+    GasPriceBelowMinimum {
+        /// Minimum required gas price.
+        minimum_gas_price: u128,
+    },
+    /// The transaction's sender or recipient is denied by the configured address filter.
+    #[error("address {0} is not allowed to transact")]
+    AddressNotAllowed(Address),
 }
 
 // === impl InvalidPoolTransactionError ===
@@ -388,6 +398,14 @@ impl InvalidPoolTransactionError {
                 Eip7702PoolTransactionError::AuthorityReserved => false,
             },
             Self::PriorityFeeBelowMinimum { .. } => false,
+            Self::GasPriceBelowMinimum { .. } => {
+                // local setting
+                false
+            }
+            Self::AddressNotAllowed(_) => {
+                // local setting
+                false
+            }
         }
     }
 
@@ -293,7 +293,11 @@ pub use crate::{
         TransactionValidator, ValidPoolTransaction,
     },
 };
-use crate::{identifier::TransactionId, pool::PoolInner};
+use crate::{
+    identifier::TransactionId,
+    pool::PoolInner,
+    validate::{AddressFilter, MinimumGasPrice},
+};
 use alloy_eips::{
     eip4844::{BlobAndProofV1, BlobAndProofV2},
     eip7594::BlobTransactionSidecarVariant,
@@ -775,6 +779,14 @@ where
     ) -> Result<Option<Vec<BlobAndProofV2>>, BlobStoreError> {
         self.pool.blob_store().get_by_versioned_hashes_v2(versioned_hashes)
     }
+
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        self.pool.validator().shared_minimum_gas_price()
+    }
+
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        self.pool.validator().shared_address_filter()
+    }
 }
 
 impl<V, T, S> TransactionPoolExt for Pool<V, T, S>
@@ -139,6 +139,8 @@ pub struct AllTransactionsMetrics {
 pub struct TxPoolValidationMetrics {
     /// How long to successfully validate a blob
     pub(crate) blob_validation_duration: Histogram,
+    /// Number of transactions rejected because their sender or recipient is not allowed
+    pub(crate) address_filter_rejections: Counter,
 }
 
 /// Transaction pool validator task metrics
@@ -1,6 +1,6 @@
 use crate::traits::PoolTransaction;
-use alloy_primitives::U256;
-use std::{cmp::Ordering, fmt::Debug, marker::PhantomData};
+use alloy_primitives::{Address, U256};
+use std::{cmp::Ordering, collections::HashSet, fmt::Debug, marker::PhantomData, sync::Arc};
 
 /// Priority of the transaction that can be missing.
 ///
@@ -63,9 +63,33 @@ pub trait TransactionOrdering: Debug + Send + Sync + 'static {
 ///
 /// The transactions are ordered by their coinbase tip.
 /// The higher the coinbase tip is, the higher the priority of the transaction.
+///
+/// Transactions sent from or to one of the configured priority addresses are ranked ahead of other
+/// transactions with the same tip, see [`Self::with_priority_addresses`]. Transactions with a
+/// higher tip still take precedence.
 #[derive(Debug)]
 #[non_exhaustive]
-pub struct CoinbaseTipOrdering<T>(PhantomData<T>);
+pub struct CoinbaseTipOrdering<T> {
+    priority_addresses: Arc<HashSet<Address>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> CoinbaseTipOrdering<T> {
+    /// Prefers transactions sent from or to the given addresses over other transactions with the
+    /// same tip.
+    pub fn with_priority_addresses(
+        mut self,
+        priority_addresses: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        self.priority_addresses = Arc::new(priority_addresses.into_iter().collect());
+        self
+    }
+
+    /// Returns the addresses whose transactions are preferred.
+    pub fn priority_addresses(&self) -> &HashSet<Address> {
+        &self.priority_addresses
+    }
+}
 
 impl<T> TransactionOrdering for CoinbaseTipOrdering<T>
 where
@@ -82,25 +106,34 @@ where
         transaction: &Self::Transaction,
         base_fee: u64,
     ) -> Priority<Self::PriorityValue> {
-        transaction.effective_tip_per_gas(base_fee).map(U256::from).into()
+        let tip = transaction.effective_tip_per_gas(base_fee).map(U256::from);
+        if self.priority_addresses.is_empty() {
+            return tip.into()
+        }
+
+        // the lowest bit ranks priority transactions ahead of transactions with the same tip
+        let is_priority = self.priority_addresses.contains(transaction.sender_ref()) ||
+            transaction.to().is_some_and(|to| self.priority_addresses.contains(&to));
+        tip.map(|tip| (tip << 1) | U256::from(is_priority)).into()
     }
 }
 
 impl<T> Default for CoinbaseTipOrdering<T> {
     fn default() -> Self {
-        Self(Default::default())
+        Self { priority_addresses: Default::default(), _marker: PhantomData }
     }
 }
 
 impl<T> Clone for CoinbaseTipOrdering<T> {
     fn clone(&self) -> Self {
-        Self::default()
+        Self { priority_addresses: self.priority_addresses.clone(), _marker: PhantomData }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::MockTransaction;
 
     #[test]
     fn test_priority_ordering() {
@@ -113,4 +146,29 @@ mod tests {
         assert!(p2 > p3); // Value(1) > None
         assert_eq!(p3, Priority::None);
     }
+
+    #[test]
+    fn test_priority_address_ordering() {
+        let priority = Address::with_last_byte(1);
+        let ordering = CoinbaseTipOrdering::default().with_priority_addresses([priority]);
+
+        let tx = MockTransaction::eip1559().with_max_fee(10).with_priority_fee(2);
+        let priority_tx = tx.clone().with_sender(priority);
+        let higher_tip_tx = tx.clone().with_priority_fee(3);
+
+        let p = ordering.priority(&tx, 1);
+        let p_priority = ordering.priority(&priority_tx, 1);
+        let p_higher_tip = ordering.priority(&higher_tip_tx, 1);
+
+        assert!(p_priority > p);
+        assert!(p_higher_tip > p_priority);
+
+        // fee cap below the base fee
+        assert_eq!(ordering.priority(&priority_tx, 11), Priority::None);
+
+        // without priority addresses only the tip matters
+        let ordering = CoinbaseTipOrdering::default();
+        assert_eq!(ordering.priority(&priority_tx, 1), ordering.priority(&tx, 1));
+        assert!(ordering.priority(&higher_tip_tx, 1) > ordering.priority(&priority_tx, 1));
+    }
 }
@@ -57,7 +57,7 @@ use crate::{
         state::SubPool, BestTransactionFilter, NewTransactionEvent, TransactionEvents,
         TransactionListenerKind,
     },
-    validate::ValidPoolTransaction,
+    validate::{AddressFilter, MinimumGasPrice, ValidPoolTransaction},
     AddedTransactionOutcome, AllTransactionsEvents,
 };
 use alloy_consensus::{error::ValueError, BlockHeader, Signed, Typed2718};
@@ -624,6 +624,23 @@ pub trait TransactionPool: Clone + Debug + Send + Sync {
         &self,
         versioned_hashes: &[B256],
     ) -> Result<Option<Vec<BlobAndProofV2>>, BlobStoreError>;
+
+    /// Returns the handle to update the minimum gas price of the pool at runtime.
+    ///
+    /// Returns `None` if the pool's validator does not support a minimum gas price, see
+    /// [`TransactionValidator::shared_minimum_gas_price`](crate::TransactionValidator::shared_minimum_gas_price).
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        None
+    }
+
+    /// Returns the handle to update the sender and recipient deny and allow lists of the pool at
+    /// runtime.
+    ///
+    /// Returns `None` if the pool's validator does not support an address filter, see
+    /// [`TransactionValidator::shared_address_filter`](crate::TransactionValidator::shared_address_filter).
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        None
+    }
 }
 
 /// Extension for [`TransactionPool`] trait that allows to set the current block info.
@@ -25,6 +25,7 @@ use alloy_eips::{
     eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M, eip4844::env_settings::EnvKzgSettings,
     eip7840::BlobParams,
 };
+use parking_lot::RwLock;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_primitives_traits::{
     constants::MAX_TX_GAS_LIMIT_OSAKA, transaction::error::InvalidTransactionError, Account, Block,
@@ -33,6 +34,7 @@ use reth_primitives_traits::{
 use reth_storage_api::{AccountInfoReader, BytecodeReader, StateProviderFactory};
 use reth_tasks::TaskSpawner;
 use std::{
+    collections::HashSet,
     marker::PhantomData,
     sync::{
         atomic::{AtomicBool, AtomicU64},
@@ -78,6 +80,20 @@ pub struct EthTransactionValidator<Client, T> {
     tx_fee_cap: Option<u128>,
     /// Minimum priority fee to enforce for acceptance into the pool.
     minimum_priority_fee: Option<u128>,
+    /// Minimum gas price that transactions must pay to be accepted into the pool.
+    ///
+    /// Can be updated at runtime, see [`Self::set_minimum_gas_price`].
+    minimum_gas_price: MinimumGasPrice,
+    /// Base fee of the next block, used to determine the gas price a transaction pays.
+    ///
+    /// `None` until the first head block is known or before London.
+    pending_base_fee: RwLock<Option<u64>>,
+    /// Senders and recipients whose transactions are exempt from the minimum fee requirements.
+    free_gas_addresses: HashSet<Address>,
+    /// Deny and allow lists of senders and recipients.
+    ///
+    /// Can be updated at runtime, see [`TransactionValidator::shared_address_filter`].
+    address_filter: AddressFilter,
     /// Stores the setup and parameters needed for validating KZG proofs.
     kzg_settings: EnvKzgSettings,
     /// How to handle [`TransactionOrigin::Local`](TransactionOrigin) transactions.
@@ -151,6 +167,29 @@ impl<Client, Tx> EthTransactionValidator<Client, Tx> {
         &self.minimum_priority_fee
     }
 
+    /// Returns the minimum gas price to enforce for acceptance into the pool
+    pub fn minimum_gas_price(&self) -> Option<u128> {
+        self.minimum_gas_price.minimum_gas_price()
+    }
+
+    /// Updates the minimum gas price to enforce for acceptance into the pool.
+    ///
+    /// Only applies to transactions validated after the update, transactions that are already in
+    /// the pool are not evicted.
+    pub fn set_minimum_gas_price(&self, minimum_gas_price: Option<u128>) {
+        self.minimum_gas_price.set_minimum_gas_price(minimum_gas_price);
+    }
+
+    /// Returns the addresses whose transactions are exempt from the minimum fee requirements
+    pub const fn free_gas_addresses(&self) -> &HashSet<Address> {
+        &self.free_gas_addresses
+    }
+
+    /// Returns the deny and allow lists of senders and recipients
+    pub const fn address_filter(&self) -> &AddressFilter {
+        &self.address_filter
+    }
+
     /// Returns the setup and parameters needed for validating KZG proofs.
     pub const fn kzg_settings(&self) -> &EnvKzgSettings {
         &self.kzg_settings
@@ -183,6 +222,25 @@ where
         self.max_gas_limit()
     }
 
+    /// Returns `true` if the transaction is sent from or to a free gas address.
+    fn is_free_gas(&self, transaction: &Tx) -> bool {
+        !self.free_gas_addresses.is_empty() &&
+            (self.free_gas_addresses.contains(transaction.sender_ref()) ||
+                transaction.to().is_some_and(|to| self.free_gas_addresses.contains(&to)))
+    }
+
+    /// Returns the gas price the transaction pays at the base fee of the next block.
+    ///
+    /// This is `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)` for dynamic fee
+    /// transactions and the gas price for legacy transactions. If the base fee of the next block
+    /// is unknown the max fee per gas is used.
+    fn effective_gas_price(&self, transaction: &Tx) -> u128 {
+        let max_fee_per_gas = transaction.max_fee_per_gas();
+        let Some(base_fee) = *self.pending_base_fee.read() else { return max_fee_per_gas };
+        let tip = transaction.max_priority_fee_per_gas().unwrap_or(max_fee_per_gas);
+        max_fee_per_gas.min((base_fee as u128).saturating_add(tip))
+    }
+
     /// Validates a single transaction.
     ///
     /// See also [`TransactionValidator::validate_transaction`]
@@ -405,9 +463,24 @@ where
             }
         }
 
+        // Drop transactions from or to addresses that are not allowed to transact.
+        if let Some(address) =
+            self.address_filter.not_allowed_address(transaction.sender(), transaction.to())
+        {
+            self.validation_metrics.address_filter_rejections.increment(1);
+            return Err(TransactionValidationOutcome::Invalid(
+                transaction,
+                InvalidPoolTransactionError::AddressNotAllowed(address),
+            ))
+        }
+
+        // Transactions from or to free gas addresses are exempt from the minimum fee requirements.
+        let is_free_gas = self.is_free_gas(&transaction);
+
         // Drop non-local transactions with a fee lower than the configured fee for acceptance into
         // the pool.
         if !is_local &&
+            !is_free_gas &&
             transaction.is_dynamic_fee() &&
             transaction.max_priority_fee_per_gas() < self.minimum_priority_fee
         {
@@ -421,6 +494,16 @@ where
             ))
         }
 
+        // Drop transactions that pay a gas price lower than the configured minimum gas price.
+        if let Some(minimum_gas_price) = self.minimum_gas_price().filter(|_| !is_free_gas) {
+            if self.effective_gas_price(&transaction) < minimum_gas_price {
+                return Err(TransactionValidationOutcome::Invalid(
+                    transaction,
+                    InvalidPoolTransactionError::GasPriceBelowMinimum { minimum_gas_price },
+                ))
+            }
+        }
+
         // Checks for chainid
         if let Some(chain_id) = transaction.chain_id() {
             if chain_id != self.chain_id() {
@@ -749,6 +832,10 @@ where
         }
 
         self.block_gas_limit.store(new_tip_block.gas_limit(), std::sync::atomic::Ordering::Relaxed);
+
+        *self.pending_base_fee.write() = new_tip_block.next_block_base_fee(
+            self.chain_spec().base_fee_params_at_timestamp(new_tip_block.timestamp()),
+        );
     }
 
     fn max_gas_limit(&self) -> u64 {
@@ -792,6 +879,14 @@ where
     {
         self.on_new_head_block(new_tip_block.header())
     }
+
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        Some(self.minimum_gas_price.clone())
+    }
+
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        Some(self.address_filter.clone())
+    }
 }
 
 /// A builder for [`EthTransactionValidator`] and [`TransactionValidationTaskExecutor`]
@@ -822,6 +917,16 @@ pub struct EthTransactionValidatorBuilder<Client> {
     tx_fee_cap: Option<u128>,
     /// Minimum priority fee to enforce for acceptance into the pool.
     minimum_priority_fee: Option<u128>,
+    /// Minimum gas price that transactions must pay to be accepted into the pool.
+    minimum_gas_price: Option<u128>,
+    /// Senders and recipients whose transactions are exempt from the minimum fee requirements.
+    free_gas_addresses: HashSet<Address>,
+    /// Senders and recipients whose transactions are rejected.
+    denied_addresses: HashSet<Address>,
+    /// If not empty, only transactions from these addresses are accepted.
+    allowed_senders: HashSet<Address>,
+    /// If not empty, only transactions to these addresses are accepted.
+    allowed_recipients: HashSet<Address>,
     /// Determines how many additional tasks to spawn
     ///
     /// Default is 1
@@ -854,6 +959,11 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
             block_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M.into(),
             client,
             minimum_priority_fee: None,
+            minimum_gas_price: None,
+            free_gas_addresses: HashSet::default(),
+            denied_addresses: HashSet::default(),
+            allowed_senders: HashSet::default(),
+            allowed_recipients: HashSet::default(),
             additional_tasks: 1,
             kzg_settings: EnvKzgSettings::Default,
             local_transactions_config: Default::default(),
@@ -984,6 +1094,60 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
         self
     }
 
+    /// Sets a minimum gas price that transactions must pay to be accepted into the pool.
+    ///
+    /// This is the initial value, the minimum gas price of the built validator can be updated at
+    /// runtime through [`TransactionValidator::shared_minimum_gas_price`].
+    pub const fn with_minimum_gas_price(mut self, minimum_gas_price: Option<u128>) -> Self {
+        self.minimum_gas_price = minimum_gas_price;
+        self
+    }
+
+    /// Sets the senders and recipients whose transactions are exempt from the minimum priority fee
+    /// and minimum gas price requirements.
+    ///
+    /// These transactions are still subject to the protocol base fee checks of the pool.
+    pub fn with_free_gas_addresses(
+        mut self,
+        free_gas_addresses: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        self.free_gas_addresses = free_gas_addresses.into_iter().collect();
+        self
+    }
+
+    /// Sets the senders and recipients whose transactions are rejected.
+    pub fn with_denied_addresses(
+        mut self,
+        denied_addresses: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        self.denied_addresses = denied_addresses.into_iter().collect();
+        self
+    }
+
+    /// Sets the addresses that transactions must be sent from.
+    ///
+    /// If empty, which is the default, transactions from all addresses that are not denied are
+    /// accepted.
+    pub fn with_allowed_senders(
+        mut self,
+        allowed_senders: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        self.allowed_senders = allowed_senders.into_iter().collect();
+        self
+    }
+
+    /// Sets the addresses that transactions must be sent to.
+    ///
+    /// If empty, which is the default, transactions to all addresses that are not denied are
+    /// accepted. Otherwise contract creations are rejected.
+    pub fn with_allowed_recipients(
+        mut self,
+        allowed_recipients: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        self.allowed_recipients = allowed_recipients.into_iter().collect();
+        self
+    }
+
     /// Sets the number of additional tasks to spawn.
     pub const fn with_additional_tasks(mut self, additional_tasks: usize) -> Self {
         self.additional_tasks = additional_tasks;
@@ -1062,6 +1226,11 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
             block_gas_limit,
             tx_fee_cap,
             minimum_priority_fee,
+            minimum_gas_price,
+            free_gas_addresses,
+            denied_addresses,
+            allowed_senders,
+            allowed_recipients,
             kzg_settings,
             local_transactions_config,
             max_tx_input_bytes,
@@ -1094,6 +1263,14 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
             block_gas_limit,
             tx_fee_cap,
             minimum_priority_fee,
+            minimum_gas_price: MinimumGasPrice::new(minimum_gas_price),
+            pending_base_fee: RwLock::new(None),
+            free_gas_addresses,
+            address_filter: AddressFilter::new(
+                denied_addresses,
+                allowed_senders,
+                allowed_recipients,
+            ),
             blob_store: Box::new(blob_store),
             kzg_settings,
             local_transactions_config,
@@ -1148,6 +1325,152 @@ impl<Client> EthTransactionValidatorBuilder<Client> {
     }
 }
 
+/// The minimum gas price that transactions must pay to be accepted by the
+/// [`EthTransactionValidator`].
+///
+/// This type is shareable and can be used to update the minimum gas price of a running validator,
+/// e.g. via the `miner_setGasPrice` RPC method. The handle of a validator is returned by
+/// [`TransactionValidator::shared_minimum_gas_price`].
+#[derive(Debug, Clone, Default)]
+pub struct MinimumGasPrice {
+    /// The minimum gas price, if any.
+    minimum_gas_price: Arc<RwLock<Option<u128>>>,
+}
+
+impl MinimumGasPrice {
+    /// Creates a new shareable minimum gas price with the given value.
+    pub fn new(minimum_gas_price: Option<u128>) -> Self {
+        Self { minimum_gas_price: Arc::new(RwLock::new(minimum_gas_price)) }
+    }
+
+    /// Returns the minimum gas price, if any.
+    pub fn minimum_gas_price(&self) -> Option<u128> {
+        *self.minimum_gas_price.read()
+    }
+
+    /// Sets the minimum gas price, `None` removes it.
+    pub fn set_minimum_gas_price(&self, minimum_gas_price: Option<u128>) {
+        *self.minimum_gas_price.write() = minimum_gas_price;
+    }
+}
+
+/// The deny and allow lists of senders and recipients enforced by the [`EthTransactionValidator`].
+///
+/// This type is shareable and can be used to update the lists of a running validator, e.g. via the
+/// `admin_addDeniedAddresses` RPC method. The handle of a validator is returned by
+/// [`TransactionValidator::shared_address_filter`].
+///
+/// Updates only apply to transactions validated afterwards, transactions that are already in the
+/// pool are not evicted by the filter itself. The `admin` RPC methods evict them after updating.
+#[derive(Debug, Clone, Default)]
+pub struct AddressFilter {
+    /// The deny and allow lists.
+    lists: Arc<RwLock<AddressLists>>,
+}
+
+/// The deny and allow lists of an [`AddressFilter`].
+#[derive(Debug, Default)]
+struct AddressLists {
+    /// Senders and recipients whose transactions are rejected.
+    denied_addresses: HashSet<Address>,
+    /// If not empty, only transactions from these addresses are accepted.
+    allowed_senders: HashSet<Address>,
+    /// If not empty, only transactions to these addresses are accepted.
+    allowed_recipients: HashSet<Address>,
+}
+
+impl AddressFilter {
+    /// Creates a new shareable address filter with the given lists.
+    pub fn new(
+        denied_addresses: HashSet<Address>,
+        allowed_senders: HashSet<Address>,
+        allowed_recipients: HashSet<Address>,
+    ) -> Self {
+        Self {
+            lists: Arc::new(RwLock::new(AddressLists {
+                denied_addresses,
+                allowed_senders,
+                allowed_recipients,
+            })),
+        }
+    }
+
+    /// Returns the addresses whose transactions are rejected.
+    pub fn denied_addresses(&self) -> HashSet<Address> {
+        self.lists.read().denied_addresses.clone()
+    }
+
+    /// Returns the addresses that transactions must be sent from, if not empty.
+    pub fn allowed_senders(&self) -> HashSet<Address> {
+        self.lists.read().allowed_senders.clone()
+    }
+
+    /// Returns the addresses that transactions must be sent to, if not empty.
+    pub fn allowed_recipients(&self) -> HashSet<Address> {
+        self.lists.read().allowed_recipients.clone()
+    }
+
+    /// Rejects transactions sent from or to the given addresses.
+    pub fn add_denied_addresses(&self, addresses: impl IntoIterator<Item = Address>) {
+        self.lists.write().denied_addresses.extend(addresses);
+    }
+
+    /// Stops rejecting transactions sent from or to the given addresses.
+    ///
+    /// Returns `true` if any of the addresses was denied.
+    pub fn remove_denied_addresses(&self, addresses: impl IntoIterator<Item = Address>) -> bool {
+        let mut lists = self.lists.write();
+        addresses
+            .into_iter()
+            .fold(false, |removed, address| lists.denied_addresses.remove(&address) || removed)
+    }
+
+    /// Replaces the addresses that transactions must be sent from.
+    ///
+    /// If empty, transactions from all addresses that are not denied are accepted.
+    pub fn set_allowed_senders(&self, addresses: impl IntoIterator<Item = Address>) {
+        self.lists.write().allowed_senders = addresses.into_iter().collect();
+    }
+
+    /// Replaces the addresses that transactions must be sent to.
+    ///
+    /// If empty, transactions to all addresses that are not denied are accepted. Otherwise
+    /// contract creations are rejected.
+    pub fn set_allowed_recipients(&self, addresses: impl IntoIterator<Item = Address>) {
+        self.lists.write().allowed_recipients = addresses.into_iter().collect();
+    }
+
+    /// Returns the sender or recipient of a transaction that is not allowed to transact, if any.
+    ///
+    /// Denied addresses take precedence over allowed addresses. If allowed senders are configured,
+    /// the sender must be one of them, and if allowed recipients are configured, the recipient
+    /// must be one of them. Contract creations have no recipient, so they are rejected with the
+    /// sender if allowed recipients are configured.
+    pub fn not_allowed_address(&self, sender: Address, to: Option<Address>) -> Option<Address> {
+        let lists = self.lists.read();
+
+        if let Some(denied) = [Some(sender), to]
+            .into_iter()
+            .flatten()
+            .find(|address| lists.denied_addresses.contains(address))
+        {
+            return Some(denied)
+        }
+
+        if !lists.allowed_senders.is_empty() && !lists.allowed_senders.contains(&sender) {
+            return Some(sender)
+        }
+
+        if lists.allowed_recipients.is_empty() {
+            return None
+        }
+        match to {
+            Some(to) => (!lists.allowed_recipients.contains(&to)).then_some(to),
+            None => Some(sender),
+        }
+    }
+}
+
 /// Keeps track of whether certain forks are activated
 #[derive(Debug)]
 pub struct ForkTracker {
@@ -1230,10 +1553,10 @@ pub fn ensure_intrinsic_gas<T: EthPoolTransaction>(
 mod tests {
     use super::*;
     use crate::{
-        blobstore::InMemoryBlobStore, error::PoolErrorKind, traits::PoolTransaction,
-        CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionPool,
+        blobstore::InMemoryBlobStore, error::PoolErrorKind, test_utils::MockTransaction,
+        traits::PoolTransaction, CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionPool,
     };
-    use alloy_consensus::Transaction;
+    use alloy_consensus::{Header, Transaction};
     use alloy_eips::eip2718::Decodable2718;
     use alloy_primitives::{hex, U256};
     use reth_ethereum_primitives::PooledTransactionVariant;
@@ -1465,6 +1788,240 @@ mod tests {
         assert!(outcome.is_valid());
     }
 
+    #[tokio::test]
+    async fn invalid_on_gas_price_lower_than_configured_minimum() {
+        let (transaction, provider) = setup_priority_fee_test();
+
+        let minimum_gas_price = transaction.max_fee_per_gas() + 1;
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_minimum_gas_price(Some(minimum_gas_price))
+            .build(InMemoryBlobStore::default());
+
+        // the minimum gas price also applies to local transactions
+        let outcome = validator.validate_one(TransactionOrigin::Local, transaction);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::GasPriceBelowMinimum { minimum_gas_price: min_price }
+            ) if min_price == minimum_gas_price
+        ));
+    }
+
+    #[tokio::test]
+    async fn invalid_on_zero_tip_below_configured_minimum() {
+        let transaction = EthPooledTransaction::new(
+            MockTransaction::eip1559()
+                .with_gas_limit(21_000)
+                .with_max_fee(1_000_000_000_000)
+                .with_priority_fee(0)
+                .into(),
+            200,
+        );
+        let provider = MockEthProvider::default();
+        provider.add_account(
+            transaction.sender(),
+            ExtendedAccount::new(transaction.nonce(), U256::MAX),
+        );
+
+        let minimum_gas_price = 10_000_000_000;
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_minimum_gas_price(Some(minimum_gas_price))
+            .build(InMemoryBlobStore::default());
+
+        // the max fee covers the minimum, so without a known base fee the transaction is accepted
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        // at a base fee of 1 gwei the transaction only pays 1 gwei
+        validator.on_new_head_block(&Header {
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        });
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::GasPriceBelowMinimum { minimum_gas_price: min_price }
+            ) if min_price == minimum_gas_price
+        ));
+    }
+
+    #[tokio::test]
+    async fn valid_on_free_gas_address_below_minimum() {
+        let (transaction, provider) = setup_priority_fee_test();
+
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_minimum_gas_price(Some(transaction.max_fee_per_gas() + 1))
+            .with_free_gas_addresses([transaction.sender()])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_minimum_gas_price(Some(transaction.max_fee_per_gas() + 1))
+            .with_free_gas_addresses(transaction.to())
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
+    #[tokio::test]
+    async fn invalid_on_not_allowed_address() {
+        let (transaction, provider) = setup_priority_fee_test();
+        let to = transaction.to().unwrap();
+
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_denied_addresses([to])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::Local, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::AddressNotAllowed(address)
+            ) if address == to
+        ));
+
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_allowed_senders([Address::ZERO])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::AddressNotAllowed(address)
+            ) if address == transaction.sender()
+        ));
+
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_allowed_recipients([to])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
+    #[tokio::test]
+    async fn invalid_on_not_allowed_sender_to_allowed_recipient() {
+        let (transaction, provider) = setup_priority_fee_test();
+        let sender = transaction.sender();
+        let to = transaction.to().unwrap();
+
+        // sending to an allowed recipient does not bypass the allowed senders
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_allowed_senders([Address::ZERO])
+            .with_allowed_recipients([to])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::AddressNotAllowed(address)
+            ) if address == sender
+        ));
+
+        // and an allowed sender does not bypass the allowed recipients
+        let validator = EthTransactionValidatorBuilder::new(provider.clone())
+            .with_allowed_senders([sender])
+            .with_allowed_recipients([Address::ZERO])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::AddressNotAllowed(address)
+            ) if address == to
+        ));
+
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_allowed_senders([sender])
+            .with_allowed_recipients([to])
+            .build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
+    #[tokio::test]
+    async fn update_address_filter() {
+        let (transaction, provider) = setup_priority_fee_test();
+        let sender = transaction.sender();
+
+        let validator =
+            EthTransactionValidatorBuilder::new(provider).build(InMemoryBlobStore::default());
+        let address_filter = validator.shared_address_filter().unwrap();
+
+        address_filter.add_denied_addresses([sender]);
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::AddressNotAllowed(address)
+            ) if address == sender
+        ));
+
+        assert!(address_filter.remove_denied_addresses([sender]));
+        assert!(!address_filter.remove_denied_addresses([sender]));
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        address_filter.set_allowed_senders([Address::ZERO]);
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(!outcome.is_valid());
+
+        address_filter.set_allowed_senders([]);
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
+    #[tokio::test]
+    async fn valid_on_gas_price_equal_to_minimum() {
+        let (transaction, provider) = setup_priority_fee_test();
+
+        let validator = EthTransactionValidatorBuilder::new(provider)
+            .with_minimum_gas_price(Some(transaction.max_fee_per_gas()))
+            .build(InMemoryBlobStore::default());
+
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
+    #[tokio::test]
+    async fn update_minimum_gas_price() {
+        let (transaction, provider) = setup_priority_fee_test();
+
+        let validator =
+            EthTransactionValidatorBuilder::new(provider).build(InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_valid());
+
+        validator.set_minimum_gas_price(Some(transaction.max_fee_per_gas() + 1));
+        assert_eq!(validator.minimum_gas_price(), Some(transaction.max_fee_per_gas() + 1));
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::GasPriceBelowMinimum { .. }
+            )
+        ));
+
+        validator.set_minimum_gas_price(None);
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+
+        // updates through the shared handle apply to the running validator
+        let minimum_gas_price = validator.shared_minimum_gas_price().unwrap();
+        minimum_gas_price.set_minimum_gas_price(Some(1));
+        assert_eq!(validator.minimum_gas_price(), Some(1));
+    }
+
     // Helper function to set up common test infrastructure for priority fee tests
     fn setup_priority_fee_test() -> (EthPooledTransaction, MockEthProvider) {
         let transaction = get_transaction();
@@ -233,6 +233,21 @@ pub trait TransactionValidator: Debug + Send + Sync {
         B: Block,
     {
     }
+
+    /// Returns the handle to update the minimum gas price enforced by this validator at runtime.
+    ///
+    /// Returns `None` if the validator does not support a minimum gas price.
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        None
+    }
+
+    /// Returns the handle to update the sender and recipient deny and allow lists enforced by this
+    /// validator at runtime.
+    ///
+    /// Returns `None` if the validator does not support an address filter.
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        None
+    }
 }
 
 impl<A, B> TransactionValidator for Either<A, B>
@@ -283,6 +298,20 @@ where
             Self::Right(v) => v.on_new_head_block(new_tip_block),
         }
     }
+
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        match self {
+            Self::Left(v) => v.shared_minimum_gas_price(),
+            Self::Right(v) => v.shared_minimum_gas_price(),
+        }
+    }
+
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        match self {
+            Self::Left(v) => v.shared_address_filter(),
+            Self::Right(v) => v.shared_address_filter(),
+        }
+    }
 }
 
 /// A valid transaction in the pool.
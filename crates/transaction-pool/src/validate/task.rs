@@ -3,7 +3,9 @@
 use crate::{
     blobstore::BlobStore,
     metrics::TxPoolValidatorMetrics,
-    validate::{EthTransactionValidatorBuilder, TransactionValidatorError},
+    validate::{
+        AddressFilter, EthTransactionValidatorBuilder, MinimumGasPrice, TransactionValidatorError,
+    },
     EthTransactionValidator, PoolTransaction, TransactionOrigin, TransactionValidationOutcome,
     TransactionValidator,
 };
@@ -284,4 +286,12 @@ where
     {
         self.validator.on_new_head_block(new_tip_block)
     }
+
+    fn shared_minimum_gas_price(&self) -> Option<MinimumGasPrice> {
+        self.validator.shared_minimum_gas_price()
+    }
+
+    fn shared_address_filter(&self) -> Option<AddressFilter> {
+        self.validator.shared_address_filter()
+    }
 }